@@ -33,6 +33,11 @@
 //! - `#[derive(EnumEvent)]` - Generates `Event` types (see examples below)
 //! - `#[derive(EnumEntityEvent)]` - Generates `EntityEvent` types (see [EnumEntityEvent examples](#enumentityevent))
 //!
+//! Buffered messages (read with `MessageReader`, written with `MessageWriter`) are not events in
+//! Bevy 0.17, so they get their own derive:
+//!
+//! - `#[derive(EnumMessage)]` - Generates `Message` types (see [EnumMessage examples](#example-enummessage))
+//!
 //! # Example: `EnumEvent` (Unit Variants)
 //!
 //! ```rust
@@ -40,61 +45,361 @@
 //! use bevy_enum_event::EnumEvent;
 //!
 //! #[derive(EnumEvent, Clone, Copy, Debug, PartialEq, Eq, Hash)]
-//! enum PlayerState {
+//! pub enum PlayerState {
 //!     Idle,
 //!     Running,
 //!     Jumping,
 //! }
 //! ```
 //!
-//! This generates:
+//! This generates, abridged:
 //!
-//! ```rust
+//! ```rust,ignore
 //! pub mod player_state {
-//!     use bevy::prelude::Event;
+//!     #[allow(unused_imports)]
+//!     use super::*;
 //!
-//!     #[derive(Event, Clone, Copy, Debug)]
+//!     #[derive(::bevy::prelude::Event, Clone, Copy, Debug, Default)]
 //!     pub struct Idle;
 //!
-//!     #[derive(Event, Clone, Copy, Debug)]
+//!     #[derive(::bevy::prelude::Event, Clone, Copy, Debug, Default)]
 //!     pub struct Running;
 //!
-//!     #[derive(Event, Clone, Copy, Debug)]
+//!     #[derive(::bevy::prelude::Event, Clone, Copy, Debug, Default)]
 //!     pub struct Jumping;
+//!
+//!     impl Idle {
+//!         pub const fn new() -> Self { Self }
+//!     }
+//!     // ...and `new` for `Running` and `Jumping`
+//! }
+//!
+//! impl PlayerState {
+//!     pub fn trigger(self, commands: &mut Commands) { /* triggers the variant's struct */ }
+//!     pub fn trigger_world(self, world: &mut World) { /* likewise, on a `World` */ }
+//!     pub fn idle_event() -> player_state::Idle { player_state::Idle::new() }
+//!     // ...and `running_event`, `jumping_event`
 //! }
+//!
+//! // ...plus `From<player_state::Idle> for PlayerState` and
+//! // `TryFrom<PlayerState> for player_state::Idle` for every variant
 //! ```
 //!
+//! Bevy's derives are named by full path, and the enum's own derives are not copied (see
+//! [Configuring Derives](#configuring-derives)).
+//!
 //! # Example: Variants with Data
 //!
 //! ```
 //! use bevy_enum_event::EnumEvent;
 //!
 //! #[derive(EnumEvent, Clone)]
-//! enum GameEvent {
+//! pub enum GameEvent {
 //!     Victory(String),
 //!     ScoreChanged { team: u32, score: i32 },
 //!     GameOver,
 //! }
 //! ```
 //!
-//! This generates:
+//! This generates, abridged:
 //!
-//! ```rust
+//! ```rust,ignore
 //! pub mod game_event {
-//!     use bevy::prelude::Event;
+//!     #[allow(unused_imports)]
+//!     use super::*;
 //!
-//!     #[derive(Event, Clone, Debug)]
-//!     pub struct Victory(pub String);
+//!     // With the default `deref` feature, single-field structs deref to their field
+//!     #[derive(::bevy::prelude::Event, ::bevy::prelude::Deref, ::bevy::prelude::DerefMut, Clone, Debug)]
+//!     pub struct Victory(#[deref] pub String);
 //!
-//!     #[derive(Event, Clone, Debug)]
+//!     #[derive(::bevy::prelude::Event, Clone, Debug)]
 //!     pub struct ScoreChanged {
 //!         pub team: u32,
 //!         pub score: i32,
 //!     }
 //!
-//!     #[derive(Event, Clone, Debug)]
+//!     #[derive(::bevy::prelude::Event, Clone, Copy, Debug, Default)]
 //!     pub struct GameOver;
+//!
+//!     impl Victory {
+//!         pub fn new(__arg0: String) -> Self { Self(__arg0) }
+//!     }
+//!
+//!     impl ScoreChanged {
+//!         pub fn new(team: u32, score: i32) -> Self { Self { team, score } }
+//!         pub fn builder() -> ScoreChangedBuilder<(), ()> { /* ... */ }
+//!     }
+//!
+//!     // Setters record which fields are set in the type parameters, and
+//!     // `build` exists once every field is set
+//!     #[must_use]
+//!     pub struct ScoreChangedBuilder<__Field0, __Field1> { /* ... */ }
+//!
+//!     impl GameOver {
+//!         pub const fn new() -> Self { Self }
+//!     }
+//! }
+//!
+//! // ...plus the same `trigger` methods, shortcuts and conversions on `GameEvent`
+//! ```
+//!
+//! Field types, default values, generic bounds and `where` clauses resolve in the enum's scope:
+//! the generated module glob-imports its parent, paths shadowed by a generated struct (such as a
+//! variant `Team(Team)`) are rewritten to `super::`, and `super::`/`self::` paths gain one more
+//! `super::`. The same applies to `propagate = ...` relationships. Declare the enum at module
+//! level when its fields use local types.
+//!
+//! # Forwarded Attributes
+//!
+//! Doc comments, `#[cfg]`, `#[cfg_attr]`, `#[deprecated]` and lint attributes on a variant are
+//! copied to its generated struct, and a variant's `cfg` also gates every generated impl and
+//! match arm that refers to the struct. The enum's doc comments and lint attributes are applied
+//! to the generated module.
+//!
+//! # Configuring Derives
+//!
+//! Generated structs derive `Clone` and `Debug` (unit structs also `Copy` and `Default`).
+//! `#[enum_event(derive(...))]` adds derives, on the enum for every struct or on a variant for
+//! its struct only, and `#[enum_event(no_derive(...))]` removes standard ones so payloads that
+//! are not `Clone` or `Debug` can be carried:
+//!
+//! ```rust
+//! use bevy_enum_event::EnumEvent;
+//!
+//! #[derive(EnumEvent)]
+//! #[enum_event(no_derive(Clone, Debug))]
+//! enum TaskEvent {
+//!     Spawned(Box<dyn Fn() + Send + Sync>),
+//!     #[enum_event(derive(PartialEq, Eq, Hash))]
+//!     Finished,
+//! }
+//! ```
+//!
+//! # Skipping Variants
+//!
+//! `#[enum_event(skip)]` on a variant generates no struct or conversions for it, and skipped
+//! variants of an `EnumEntityEvent` need no target field. On enums with skipped variants, the
+//! `trigger` and `write` methods return `Result<(), Self>`: a skipped variant dispatches nothing,
+//! not even the `Any` event, and is handed back as `Err(self)`:
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use bevy_enum_event::EnumEntityEvent;
+//!
+//! #[derive(EnumEntityEvent, Clone, Copy)]
+//! enum PlayerEvent {
+//!     Damaged { entity: Entity, amount: u32 },
+//!     #[enum_event(skip)]
+//!     Reset,
+//! }
+//!
+//! let mut world = World::new();
+//! assert!(PlayerEvent::Reset.trigger_world(&mut world).is_err());
+//! ```
+//!
+//! # Triggering from Enum Values
+//!
+//! Both derives generate `trigger` and `trigger_world` methods on the enum that move the
+//! variant's data into the matching generated struct and trigger it:
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use bevy_enum_event::EnumEvent;
+//!
+//! #[derive(EnumEvent, Clone)]
+//! enum GameEvent {
+//!     ScoreChanged { team: u32, score: i32 },
+//!     GameOver,
+//! }
+//!
+//! fn report(mut commands: Commands) {
+//!     // Triggers `game_event::ScoreChanged { team: 1, score: 10 }`
+//!     GameEvent::ScoreChanged { team: 1, score: 10 }.trigger(&mut commands);
+//! }
+//!
+//! let mut world = World::new();
+//! GameEvent::GameOver.trigger_world(&mut world);
+//! ```
+//!
+//! # Umbrella `Any` Event
+//!
+//! Add `#[enum_event(any)]` to also generate an `Any` event carrying the whole enum value.
+//! The generated `trigger`/`trigger_world` methods fire it right after the variant event, so a
+//! single observer can see every variant. This requires the enum to implement `Clone`.
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use bevy_enum_event::EnumEvent;
+//!
+//! #[derive(EnumEvent, Clone, Debug)]
+//! #[enum_event(any)]
+//! enum PlayerState {
+//!     Idle,
+//!     Running,
+//! }
+//!
+//! fn log_state(state: On<player_state::Any>) {
+//!     println!("Player state changed to {:?}", state.0);
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! For `EnumEntityEvent`, `Any` is itself an `EntityEvent` with an `entity` target and a `value`
+//! field, and it uses the enum-level propagation settings. Because `Any` names the enum from
+//! inside the generated module, the enum must be declared at module level rather than inside a
+//! function body.
+//!
+//! # Converting Between Enum and Events
+//!
+//! Every generated struct converts into its enum with `From`, and the enum converts back
+//! into a generated struct with `TryFrom`, returning the original value on a variant mismatch:
+//!
+//! ```rust
+//! use bevy_enum_event::EnumEvent;
+//!
+//! #[derive(EnumEvent, Clone, Debug, PartialEq)]
+//! enum GameEvent {
+//!     ScoreChanged { team: u32, score: i32 },
+//!     GameOver,
+//! }
+//!
+//! let event: GameEvent = game_event::ScoreChanged { team: 1, score: 10 }.into();
+//! assert_eq!(event, GameEvent::ScoreChanged { team: 1, score: 10 });
+//!
+//! let score = game_event::ScoreChanged::try_from(event).unwrap();
+//! assert_eq!(score.score, 10);
+//!
+//! assert_eq!(
+//!     game_event::ScoreChanged::try_from(GameEvent::GameOver).unwrap_err(),
+//!     GameEvent::GameOver
+//! );
+//! ```
+//!
+//! # Constructors and Builders
+//!
+//! Every generated struct has a `new` constructor taking its fields in order, and the enum has
+//! a matching shortcut named after the variant, such as `damaged_event`. These names are
+//! reserved on the enum, and variants whose shortcuts coincide (`HTTPError` and `HttpError`)
+//! are rejected. Structs with named fields also get a builder, unless one of those fields is
+//! named `build` and would clash with the builder's terminal method. Setters can be called in
+//! any order, and `build` only becomes available once every field without a default is set,
+//! so a missing field is a compile error rather than a runtime panic:
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use bevy_enum_event::EnumEntityEvent;
+//!
+//! #[derive(EnumEntityEvent, Clone, Copy)]
+//! enum PlayerEvent {
+//!     Damaged { entity: Entity, amount: f32 },
+//! }
+//!
+//! fn hit(mut commands: Commands, player: Entity) {
+//!     commands.trigger(player_event::Damaged::new(player, 5.0));
+//!     commands.trigger(PlayerEvent::damaged_event(player, 5.0));
+//!     commands.trigger(
+//!         player_event::Damaged::builder()
+//!             .entity(player)
+//!             .amount(5.0)
+//!             .build(),
+//!     );
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! # use bevy::prelude::*;
+//! # use bevy_enum_event::EnumEntityEvent;
+//! # #[derive(EnumEntityEvent, Clone, Copy)]
+//! # enum PlayerEvent {
+//! #     Damaged { entity: Entity, amount: f32 },
+//! # }
+//! // `amount` was never set, so there is no `build` to call
+//! let damaged = player_event::Damaged::builder()
+//!     .entity(Entity::PLACEHOLDER)
+//!     .build();
+//! ```
+//!
+//! # Default Values
+//!
+//! Mark fields with `#[enum_event(default)]` or `#[enum_event(default = expr)]` to leave them out
+//! of the generated `new` constructor. `#[enum_event(default)]` on a variant implements `Default`
+//! for its struct, using `Default::default()` for unmarked fields. Entity events need a default
+//! target for this, such as `#[enum_event(default = Entity::PLACEHOLDER)]`:
+//!
+//! ```rust
+//! use bevy_enum_event::EnumEvent;
+//!
+//! #[derive(EnumEvent, Clone)]
+//! enum TuningEvent {
+//!     #[enum_event(default)]
+//!     AudioChanged {
+//!         #[enum_event(default = 0.8)]
+//!         volume: f32,
+//!         muted: bool,
+//!     },
+//! }
+//!
+//! assert_eq!(tuning_event::AudioChanged::default().volume, 0.8);
+//! assert!(tuning_event::AudioChanged::new(true).muted);
+//! ```
+//!
+//! # Module Name and Placement
+//!
+//! The generated module is named after the enum in snake_case. Override it with
+//! `#[enum_event(module = "name")]`, or use `#[enum_event(module = self)]` to emit the
+//! generated structs directly next to the enum:
+//!
+//! ```rust
+//! use bevy_enum_event::EnumEvent;
+//!
+//! #[derive(EnumEvent, Clone, Copy)]
+//! #[enum_event(module = "http_v2")]
+//! enum HttpEvent {
+//!     Request,
+//! }
+//!
+//! #[derive(EnumEvent, Clone, Copy)]
+//! #[enum_event(module = self)]
+//! enum Lifecycle {
+//!     Booted,
+//! }
+//!
+//! let _request = http_v2::Request;
+//! let _booted = Booted;
+//! ```
+//!
+//! The generated module, structs and fields inherit the enum's visibility (a private enum
+//! yields a private module whose items are visible to the enum's parent module). Override it
+//! with `#[enum_event(vis = "pub(crate)")]`.
+//!
+//! With `module = self`, helpers other than the variant structs are prefixed with the enum's
+//! name (`LifecycleAny`, `lifecycle_add_messages`, ...) so several enums can share a scope.
+//! `#[enum_event(flatten)]` is shorthand for `module = self`, usually combined with a `prefix` or
+//! `suffix` (see below) so flattened struct names don't clash.
+//!
+//! The module name must be a single identifier. A derive can only emit items next to its enum,
+//! so to group the events of several enums under a common parent module, declare the enums
+//! inside that module (`events::player::Damaged`, `events::game::Started`).
+//!
+//! # Struct Names
+//!
+//! Generated structs are named after their variants. `#[enum_event(prefix = "...")]` and
+//! `#[enum_event(suffix = "...")]` on the enum decorate every name, and
+//! `#[enum_event(rename = "...")]` on a variant sets its struct's name exactly:
+//!
+//! ```rust
+//! use bevy_enum_event::EnumEvent;
+//!
+//! #[derive(EnumEvent, Clone, Copy)]
+//! #[enum_event(suffix = "Event")]
+//! enum PlayerState {
+//!     Idle,
+//!     #[enum_event(rename = "PlayerJumped")]
+//!     Jumping,
 //! }
+//!
+//! let _idle = player_state::IdleEvent;
+//! let _jumped = player_state::PlayerJumped;
 //! ```
 //!
 //! # Deref Feature (enabled by default)
@@ -102,9 +407,16 @@
 //! The `deref` feature provides ergonomic access to event data:
 //!
 //! - **Single-field variants**: Automatically implement `Deref`/`DerefMut` to the inner value
-//! - **Multi-field variants**: Mark one field with `#[enum_event(deref)]` for deref access
+//! - **Multi-field variants**: Mark one field with `#[enum_event(deref)]` for read-only `Deref`
+//!   access, or `#[enum_event(deref_mut)]` (or Bevy's bare `#[deref]`) for `DerefMut` as well
 //! - **No annotation**: Access fields directly by name
 //!
+//! `#[enum_event(deref_mut = false)]` on the enum or a variant makes the automatic single-field
+//! deref read-only, so observers cannot modify an event that continues to propagate.
+//!
+//! With `#[enum_event(deref_payload)]`, entity events deref to their only field besides the
+//! target, so `Damaged { entity: Entity, amount: f32 }` derefs to `amount` without a marker.
+//!
 #![cfg_attr(
     feature = "deref",
     doc = r#"
@@ -144,6 +456,11 @@ assert_eq!(team_score.points, 50);
 //! bevy_enum_event = { version = "0.2", default-features = false }
 //! ```
 //!
+//! Since Cargo unifies features across a workspace, an enum can also choose for itself:
+//! `#[enum_event(deref = false)]` or `#[enum_event(deref = true)]` on the enum overrides the
+//! feature, and the same key on a variant overrides the enum. A field marked for deref in a
+//! variant where `deref = false` applies is an error.
+//!
 //! # Example: `EnumEntityEvent`
 //!
 //! ```rust
@@ -166,9 +483,40 @@ assert_eq!(team_score.points, 50);
 //!
 //! ## Requirements
 //!
-//! - **Named fields only**: All variants must use `{ field: Type }` syntax
-//! - **Entity field**: Each variant needs an `entity: Entity` field or a field marked `#[enum_event(target)]`
-//! - **Triggering**: Use `commands.trigger(event)` or `world.trigger(event)`
+//! - **Fields required**: Unit variants need a shared target field (see below), as every event
+//!   needs a target
+//! - **Entity field**: Each variant not marked `#[enum_event(skip)]` or `#[enum_event(global)]`
+//!   needs an `entity: Entity` field or a field marked `#[enum_event(target)]`; tuple variants may
+//!   instead have a single `Entity` field
+//! - **Triggering**: Use `commands.trigger(event)` or `world.trigger(event)`, or call
+//!   `trigger`/`trigger_world` on an enum value to target the variant's entity
+//!
+//! ## Shared Fields
+//!
+//! `#[enum_event(fields(...))]` injects fields into every generated struct, ahead of the
+//! variant's own fields. A shared `entity` field (or one marked `#[enum_event(target)]`) is the
+//! target of every variant, so even unit variants become entity events. The dispatch methods
+//! take the shared fields after the commands or world, each struct gets a `new` constructor
+//! taking them first, and `try_from_enum` replaces `TryFrom`:
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use bevy_enum_event::EnumEntityEvent;
+//!
+//! #[derive(EnumEntityEvent, Clone, Copy)]
+//! #[enum_event(fields(entity: Entity, timestamp: f64))]
+//! enum DoorEvent {
+//!     Opened,
+//!     Locked { code: u32 },
+//! }
+//!
+//! fn open(mut commands: Commands, door: Entity) {
+//!     DoorEvent::Opened.trigger(&mut commands, door, 0.0);
+//!     commands.trigger(door_event::Locked::new(door, 1.0, 42));
+//! }
+//! ```
+//!
+//! Shared fields work with every derive, but not with tuple variants or `fan_out`.
 //!
 //! ## Custom Target Field
 //!
@@ -189,6 +537,33 @@ assert_eq!(team_score.points, 50);
 //! }
 //! ```
 //!
+//! ## Mixing Global and Entity Variants
+//!
+//! One enum can hold both kinds of events. `EnumEvent` variants stay global unless they opt in:
+//! `#[enum_event(entity)]` makes a variant an entity event targeting its `entity` field (or its
+//! only `Entity` field for tuple variants), and a field marked `#[enum_event(target)]` does the
+//! same for that field. A shared field marked `target` opts in every variant. In
+//! `EnumEntityEvent`, `#[enum_event(global)]` makes a variant a global event. Propagation is only
+//! accepted on entity variants, and enum-level `propagate` applies to the entity variants of
+//! either derive:
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use bevy_enum_event::EnumEvent;
+//!
+//! #[derive(EnumEvent, Clone, Copy)]
+//! enum GameEvent {
+//!     Started,                                  // global `Event`
+//!     #[enum_event(entity)]
+//!     Damaged { entity: Entity, amount: f32 }, // `EntityEvent` targeting `entity`
+//!     Healed(#[enum_event(target)] Entity),     // `EntityEvent` targeting the marked field
+//!     Spawned { entity: Entity },               // global `Event`, `entity` is a plain field
+//! }
+//! ```
+//!
+//! With `any`, the umbrella event follows the derive: it stays global for `EnumEvent`, while
+//! `EnumEntityEvent` needs every variant to target an entity.
+//!
 //! ## Event Propagation
 //!
 //! Enable event propagation to bubble events up entity hierarchies:
@@ -221,11 +596,79 @@ assert_eq!(team_score.points, 50);
 //!
 //! **Note**: Custom relationship types must be `pub` or use absolute paths (`::bevy::`, `crate::`)
 //! because they're accessed from the generated module.
+//!
+//! # Example: `EnumMessage`
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use bevy_enum_event::EnumMessage;
+//!
+//! #[derive(EnumMessage, Clone, Copy)]
+//! enum InputAction {
+//!     Jump,
+//!     Move { x: f32, y: f32 },
+//! }
+//!
+//! fn apply_moves(mut moves: MessageReader<input_action::Move>) {
+//!     for movement in moves.read() {
+//!         println!("Moving by ({}, {})", movement.x, movement.y);
+//!     }
+//! }
+//!
+//! let mut app = App::new();
+//! // Calls `app.add_message::<T>()` for every generated message type
+//! input_action::add_messages(&mut app);
+//! app.add_systems(Update, apply_moves);
+//!
+//! // Write the message matching an enum value
+//! InputAction::Jump.write_world(app.world_mut());
+//! ```
+//!
+//! Add `#[enum_event(event, message)]` (to any of the derives) to make every generated struct
+//! both an event and a message. The enum then also gets `trigger_and_write` and
+//! `trigger_and_write_world`, which trigger the event and write the message in one call.
+//!
+//! # Fanning Out Enum Messages
+//!
+//! When the enum itself is sent as a buffered [`Message`](https://docs.rs/bevy/latest/bevy/ecs/message/trait.Message.html),
+//! `#[enum_event(fan_out)]` generates a `FanOutPlugin` that reads every enum message and
+//! triggers it as its per-variant event (on the target entity for `EnumEntityEvent`):
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use bevy_enum_event::EnumEvent;
+//!
+//! #[derive(EnumEvent, Message, Clone)]
+//! #[enum_event(fan_out)]
+//! enum PlayerState {
+//!     Idle,
+//!     Paused,
+//! }
+//!
+//! fn setup(app: &mut App) {
+//!     // Runs in `Update` unless another schedule is given with `FanOutPlugin::new`
+//!     app.add_plugins(player_state::FanOutPlugin::new(PreUpdate));
+//!     app.add_observer(|_: On<player_state::Paused>| println!("paused"));
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! The reverse, `#[enum_event(fan_in)]`, generates a `FanInPlugin` that observes every
+//! generated struct and writes the reconstructed enum as a message, preserving trigger order.
+//!
+//! For both options the enum must implement `Message` and `Clone`, be declared at module level
+//! and have no generic parameters. They cannot be combined on the same enum.
 
 use proc_macro::TokenStream;
 use quote::quote;
 use std::collections::HashSet;
-use syn::{parse_macro_input, visit::Visit, Attribute, Data, DeriveInput, Fields};
+use syn::{
+    parse_macro_input,
+    spanned::Spanned,
+    visit::Visit,
+    visit_mut::{self, VisitMut},
+    Attribute, Data, DeriveInput, Fields,
+};
 
 /// Converts `PascalCase` or `camelCase` to `snake_case`.
 ///
@@ -295,47 +738,435 @@ impl<'ast> Visit<'ast> for GenericsUsageCollector<'_> {
     }
 }
 
+/// Rewrites paths written in the enum's scope so they resolve from inside the generated module.
+///
+/// The generated module glob-imports the enum's scope, so only names it defines itself (the
+/// variant structs and helpers) resolve to the wrong item and are prefixed with `super::`.
+/// Paths relative to the enum's module (`super::`, `self::`) move up one level as well.
+struct ShadowedPathRewriter<'a> {
+    shadowed_names: &'a HashSet<String>,
+    generic_param_names: &'a [String],
+    /// Whether the generated items live in a module below the enum.
+    in_module: bool,
+}
+
+impl ShadowedPathRewriter<'_> {
+    fn rewrite(&self, path: &mut syn::Path) {
+        if path.leading_colon.is_some() || !self.in_module {
+            return;
+        }
+        let Some(first) = path.segments.first_mut() else {
+            return;
+        };
+        let span = first.ident.span();
+        if first.ident == "self" {
+            first.ident = syn::Ident::new("super", span);
+            return;
+        }
+        if first.ident == "super" {
+            path.segments
+                .insert(0, syn::PathSegment::from(syn::Ident::new("super", span)));
+            return;
+        }
+        let name = first.ident.to_string();
+        let is_generic_param =
+            path.segments.len() == 1 && self.generic_param_names.iter().any(|param| param == &name);
+        if self.shadowed_names.contains(&name) && !is_generic_param {
+            path.segments
+                .insert(0, syn::PathSegment::from(syn::Ident::new("super", span)));
+        }
+    }
+}
+
+impl VisitMut for ShadowedPathRewriter<'_> {
+    fn visit_type_path_mut(&mut self, type_path: &mut syn::TypePath) {
+        if type_path.qself.is_none() {
+            self.rewrite(&mut type_path.path);
+        }
+        visit_mut::visit_type_path_mut(self, type_path);
+    }
+
+    fn visit_trait_bound_mut(&mut self, bound: &mut syn::TraitBound) {
+        self.rewrite(&mut bound.path);
+        visit_mut::visit_trait_bound_mut(self, bound);
+    }
+
+    fn visit_expr_path_mut(&mut self, expr_path: &mut syn::ExprPath) {
+        if expr_path.qself.is_none() {
+            self.rewrite(&mut expr_path.path);
+        }
+        visit_mut::visit_expr_path_mut(self, expr_path);
+    }
+
+    fn visit_expr_struct_mut(&mut self, expr_struct: &mut syn::ExprStruct) {
+        if expr_struct.qself.is_none() {
+            self.rewrite(&mut expr_struct.path);
+        }
+        visit_mut::visit_expr_struct_mut(self, expr_struct);
+    }
+
+    fn visit_pat_struct_mut(&mut self, pat_struct: &mut syn::PatStruct) {
+        if pat_struct.qself.is_none() {
+            self.rewrite(&mut pat_struct.path);
+        }
+        visit_mut::visit_pat_struct_mut(self, pat_struct);
+    }
+
+    fn visit_pat_tuple_struct_mut(&mut self, pat_tuple_struct: &mut syn::PatTupleStruct) {
+        if pat_tuple_struct.qself.is_none() {
+            self.rewrite(&mut pat_tuple_struct.path);
+        }
+        visit_mut::visit_pat_tuple_struct_mut(self, pat_tuple_struct);
+    }
+}
+
 fn path_ends_with_ident(path: &syn::Path, ident: &str) -> bool {
     path.segments
         .last()
         .is_some_and(|segment| segment.ident == ident)
 }
 
+/// Collects diagnostics so every problem in the input is reported in a single compilation.
+#[derive(Default)]
+struct Errors(Option<syn::Error>);
+
+impl Errors {
+    fn push(&mut self, error: syn::Error) {
+        match &mut self.0 {
+            Some(errors) => errors.combine(error),
+            None => self.0 = Some(error),
+        }
+    }
+
+    fn finish(self) -> syn::Result<()> {
+        self.0.map_or(Ok(()), Err)
+    }
+}
+
 #[derive(Default)]
 struct FieldAttrInfo {
     passthrough_attrs: Vec<Attribute>,
     has_deref: bool,
     has_deref_mut: bool,
     is_event_target: bool,
+    /// `Some(None)` for a bare `default`, `Some(Some(expr))` for `default = expr`.
+    default: Option<Option<syn::Expr>>,
+}
+
+/// The Bevy trait implemented by the generated structs.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum DeriveKind {
+    Event,
+    EntityEvent,
+    Message,
+}
+
+/// Token fragments that move data between an enum variant and its generated struct.
+struct VariantConversion {
+    /// Name of the generated struct inside the generated module.
+    struct_ident: syn::Ident,
+    /// The generated struct type, including the enum's generics.
+    struct_type: proc_macro2::TokenStream,
+    /// Pattern binding every field of the enum variant.
+    variant_pattern: proc_macro2::TokenStream,
+    /// Expression building the generated struct from the bound fields.
+    struct_expr: proc_macro2::TokenStream,
+    /// Pattern binding every field of the generated struct.
+    struct_pattern: proc_macro2::TokenStream,
+    /// Expression rebuilding the enum variant from the bound fields.
+    variant_expr: proc_macro2::TokenStream,
+    /// Binding holding the event target, for entity events.
+    target_binding: Option<proc_macro2::TokenStream>,
+    /// Whether the generated entity event propagates, reaching global observers once per hop.
+    propagates: bool,
+    /// The variant's `cfg` attributes.
+    cfg_attrs: Vec<Attribute>,
+    /// Name of the enum-side shortcut building the struct, e.g. `damaged_event`.
+    shortcut_ident: syn::Ident,
+    /// Parameters of the struct's `new`, with the types as written in the enum.
+    constructor_params: Vec<proc_macro2::TokenStream>,
+    /// Arguments forwarding the shortcut's parameters to `new`.
+    constructor_args: Vec<syn::Ident>,
 }
 
 #[derive(Default)]
 struct VariantAttrInfo {
     propagate_value: Option<proc_macro2::TokenStream>,
     has_auto_propagate: bool,
+    derives: Vec<syn::Path>,
+    no_derives: HashSet<String>,
+    /// Docs, `cfg`, `cfg_attr`, `deprecated` and lint attributes copied onto the generated struct.
+    forwarded_attrs: Vec<Attribute>,
+    /// The variant's `cfg` attributes, repeated on every generated item naming its struct.
+    cfg_attrs: Vec<Attribute>,
+    has_docs: bool,
+    skip: bool,
+    rename: Option<syn::Ident>,
+    /// Span of `global`, which makes the variant a global event.
+    global_span: Option<proc_macro2::Span>,
+    /// Span of `entity`, which makes the variant an entity event.
+    entity_span: Option<proc_macro2::Span>,
+    /// `deref = true/false`, overriding the enum setting and the `deref` feature.
+    deref: Option<bool>,
+    /// `deref_mut = true/false`, overriding the enum setting for an unmarked single field.
+    deref_mut: Option<bool>,
+    /// Span of `default`, which implements `Default` for the generated struct.
+    default_span: Option<proc_macro2::Span>,
 }
 
-fn analyze_field_attrs(attrs: &[Attribute]) -> FieldAttrInfo {
-    let mut info = FieldAttrInfo::default();
+/// Attributes forwarded from variants to generated structs and from the enum to its module.
+///
+/// Other attributes usually belong to the enum's own derives (such as `#[default]` or
+/// `#[serde(...)]`) and would not apply to a struct.
+const FORWARDED_ATTRS: [&str; 9] = [
+    "doc",
+    "cfg",
+    "cfg_attr",
+    "deprecated",
+    "allow",
+    "expect",
+    "warn",
+    "deny",
+    "forbid",
+];
 
-    for attr in attrs {
-        if path_ends_with_ident(attr.path(), "enum_event") {
-            if let Err(err) = attr.parse_nested_meta(|meta| {
-                if path_ends_with_ident(&meta.path, "deref") {
-                    info.has_deref = true;
-                } else if path_ends_with_ident(&meta.path, "deref_mut") {
-                    info.has_deref_mut = true;
-                    info.has_deref = true;
-                } else if path_ends_with_ident(&meta.path, "target") {
-                    info.is_event_target = true;
-                }
+/// Returns whether `attr` is one of the [`FORWARDED_ATTRS`].
+fn is_forwarded_attr(attr: &Attribute) -> bool {
+    attr.path()
+        .get_ident()
+        .is_some_and(|ident| FORWARDED_ATTRS.iter().any(|name| ident == name))
+}
+
+/// Standard derives that `no_derive(...)` can remove from the generated structs.
+const OPTIONAL_DERIVES: [&str; 4] = ["Clone", "Copy", "Debug", "Default"];
+
+/// Parses the paths listed in `derive(...)`.
+fn parse_derive_list(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Vec<syn::Path>> {
+    let mut paths = Vec::new();
+    meta.parse_nested_meta(|nested| {
+        paths.push(nested.path);
+        Ok(())
+    })?;
+    Ok(paths)
+}
+
+/// Parses the derives listed in `no_derive(...)`, which must be among [`OPTIONAL_DERIVES`].
+fn parse_no_derive_list(meta: &syn::meta::ParseNestedMeta) -> syn::Result<HashSet<String>> {
+    let mut names = HashSet::new();
+    meta.parse_nested_meta(
+        |nested| match nested.path.get_ident().map(ToString::to_string) {
+            Some(name) if OPTIONAL_DERIVES.contains(&name.as_str()) => {
+                names.insert(name);
+                Ok(())
+            }
+            _ => Err(nested.error("no_derive accepts only `Clone`, `Copy`, `Debug` and `Default`")),
+        },
+    )?;
+    Ok(names)
+}
+
+/// Parses the `true`/`false` value of a switch such as `deref = false`.
+fn parse_bool_value(meta: &syn::meta::ParseNestedMeta) -> syn::Result<bool> {
+    let key = meta
+        .path
+        .get_ident()
+        .map(ToString::to_string)
+        .unwrap_or_default();
+    let value = meta
+        .value()
+        .map_err(|_| meta.error(format!("expected `{key} = true` or `{key} = false`")))?;
+    let lit: syn::LitBool = value.parse()?;
+    Ok(lit.value)
+}
+
+/// Lists the standard and user-requested derives for one generated struct.
+///
+/// Unit structs derive `Clone, Copy, Debug, Default` and all others `Clone, Debug`, minus the
+/// derives removed with `no_derive(...)`. `Copy` is dropped along with `Clone`, and requested
+/// derives that are already present are not repeated.
+fn collect_struct_derives<'a>(
+    is_unit: bool,
+    no_derives: &HashSet<String>,
+    extra_derives: impl IntoIterator<Item = &'a syn::Path>,
+) -> Vec<proc_macro2::TokenStream> {
+    let standard: &[&str] = if is_unit {
+        &["Clone", "Copy", "Debug", "Default"]
+    } else {
+        &["Clone", "Debug"]
+    };
+    let mut names: Vec<String> = Vec::new();
+    let mut derives = Vec::new();
+    for name in standard {
+        let removed =
+            no_derives.contains(*name) || (*name == "Copy" && no_derives.contains("Clone"));
+        if !removed {
+            let ident = syn::Ident::new(name, proc_macro2::Span::call_site());
+            names.push((*name).to_string());
+            derives.push(quote! { #ident });
+        }
+    }
+    for path in extra_derives {
+        let name = path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string())
+            .unwrap_or_default();
+        if !names.contains(&name) {
+            names.push(name);
+            derives.push(quote! { #path });
+        }
+    }
+    derives
+}
+
+/// Where an `#[enum_event(...)]` attribute is placed.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum AttrLevel {
+    Enum,
+    Variant,
+    Field,
+}
+
+/// Keys accepted at each level.
+const ENUM_KEYS: [&str; 18] = [
+    "any",
+    "fields",
+    "module",
+    "flatten",
+    "vis",
+    "prefix",
+    "suffix",
+    "derive",
+    "no_derive",
+    "event",
+    "message",
+    "fan_out",
+    "fan_in",
+    "deref",
+    "deref_mut",
+    "deref_payload",
+    "propagate",
+    "auto_propagate",
+];
+const VARIANT_KEYS: [&str; 11] = [
+    "skip",
+    "rename",
+    "derive",
+    "no_derive",
+    "global",
+    "entity",
+    "propagate",
+    "auto_propagate",
+    "deref",
+    "deref_mut",
+    "default",
+];
+const FIELD_KEYS: [&str; 4] = ["deref", "deref_mut", "target", "default"];
+
+/// Validates the keys of the `#[enum_event(...)]` attributes on one enum, variant or field.
+struct KeyChecker {
+    level: AttrLevel,
+    seen: HashSet<String>,
+}
+
+impl KeyChecker {
+    fn new(level: AttrLevel) -> Self {
+        Self {
+            level,
+            seen: HashSet::new(),
+        }
+    }
+
+    /// Errors on keys that are unknown or repeated.
+    fn check(&mut self, meta: &syn::meta::ParseNestedMeta) -> syn::Result<()> {
+        let (valid, level_name): (&[&str], _) = match self.level {
+            AttrLevel::Enum => (&ENUM_KEYS, "enum"),
+            AttrLevel::Variant => (&VARIANT_KEYS, "variant"),
+            AttrLevel::Field => (&FIELD_KEYS, "field"),
+        };
+        let Some(name) = meta.path.get_ident().map(ToString::to_string) else {
+            return Err(syn::Error::new_spanned(
+                &meta.path,
+                format!("expected an enum_event key such as `{}`", valid[0]),
+            ));
+        };
+        if !valid.contains(&name.as_str()) {
+            let message = if let Some(closest) = closest_key(&name, valid) {
+                format!("unknown {level_name} enum_event key `{name}`; did you mean `{closest}`?")
+            } else {
+                let expected: Vec<_> = valid.iter().map(|key| format!("`{key}`")).collect();
+                format!(
+                    "unknown {level_name} enum_event key `{name}`; expected one of {}",
+                    expected.join(", ")
+                )
+            };
+            return Err(syn::Error::new_spanned(&meta.path, message));
+        }
+        if !self.seen.insert(name.clone()) {
+            return Err(syn::Error::new_spanned(
+                &meta.path,
+                format!("duplicate enum_event key `{name}`"),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Returns the key in `keys` closest to a misspelled `name`, if any is close enough.
+fn closest_key<'a>(name: &str, keys: &[&'a str]) -> Option<&'a str> {
+    keys.iter()
+        .map(|key| (edit_distance(name, key), *key))
+        .filter(|(distance, key)| *distance <= 2.max(key.len() / 3))
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, key)| key)
+}
+
+/// Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+fn analyze_field_attrs(attrs: &[Attribute], errors: &mut Errors) -> FieldAttrInfo {
+    let mut info = FieldAttrInfo::default();
+    let mut keys = KeyChecker::new(AttrLevel::Field);
+
+    for attr in attrs {
+        if path_ends_with_ident(attr.path(), "enum_event") {
+            if let Err(err) = attr.parse_nested_meta(|meta| {
+                keys.check(&meta)?;
+                if path_ends_with_ident(&meta.path, "deref") {
+                    info.has_deref = true;
+                } else if path_ends_with_ident(&meta.path, "deref_mut") {
+                    info.has_deref_mut = true;
+                    info.has_deref = true;
+                } else if path_ends_with_ident(&meta.path, "target") {
+                    info.is_event_target = true;
+                } else if path_ends_with_ident(&meta.path, "default") {
+                    info.default = Some(if meta.input.peek(syn::Token![=]) {
+                        Some(meta.value()?.parse()?)
+                    } else {
+                        None
+                    });
+                }
                 Ok(())
             }) {
-                panic!("EnumEvent: failed to parse #[enum_event(...)] attribute: {err}");
+                errors.push(err);
             }
         } else if path_ends_with_ident(attr.path(), "event_target") {
             info.is_event_target = true;
         } else if path_ends_with_ident(attr.path(), "deref") {
+            // Bevy's own marker has always meant `Deref` and `DerefMut`
+            info.has_deref_mut = true;
             info.has_deref = true;
         } else if path_ends_with_ident(attr.path(), "deref_mut") {
             info.has_deref_mut = true;
@@ -348,39 +1179,1182 @@ fn analyze_field_attrs(attrs: &[Attribute]) -> FieldAttrInfo {
     info
 }
 
-fn analyze_variant_attrs(attrs: &[Attribute]) -> VariantAttrInfo {
-    let mut info = VariantAttrInfo::default();
+/// Finds the index of the field an entity event variant targets.
+///
+/// Named variants target their `entity` field (see [`is_entity_field`]) or the field marked
+/// `#[enum_event(target)]`.
+/// Tuple variants target the marked field, or their only field of type `Entity`.
+fn find_target_field(
+    variant: &syn::Variant,
+    field_attr_infos: &[FieldAttrInfo],
+    errors: &mut Errors,
+) -> Option<usize> {
+    let variant_ident = &variant.ident;
+    let marked_or_named: Vec<_> = variant
+        .fields
+        .iter()
+        .zip(field_attr_infos)
+        .enumerate()
+        .filter(|(_, (field, info))| info.is_event_target || is_entity_field(field))
+        .map(|(index, (field, _))| (index, field))
+        .collect();
+    let candidates = match &variant.fields {
+        Fields::Unit => {
+            errors.push(syn::Error::new(
+                variant_ident.span(),
+                format!("variant `{variant_ident}` is a unit variant; entity events need an `entity: Entity` field"),
+            ));
+            return None;
+        }
+        Fields::Named(_) => {
+            if marked_or_named.is_empty() {
+                errors.push(syn::Error::new(
+                    variant_ident.span(),
+                    format!("variant `{variant_ident}` must have an `entity: Entity` field or a field marked with #[enum_event(target)]"),
+                ));
+            }
+            marked_or_named
+        }
+        Fields::Unnamed(fields) if marked_or_named.is_empty() => {
+            let entity_fields: Vec<_> = fields
+                .unnamed
+                .iter()
+                .enumerate()
+                .filter(|(_, field)| is_entity_type(&field.ty))
+                .collect();
+            match entity_fields.len() {
+                0 => {
+                    errors.push(syn::Error::new(
+                        fields.span(),
+                        format!("tuple variant `{variant_ident}` needs an `Entity` field or a field marked with #[enum_event(target)]"),
+                    ));
+                    return None;
+                }
+                1 => entity_fields,
+                _ => {
+                    errors.push(syn::Error::new(
+                        fields.span(),
+                        format!("tuple variant `{variant_ident}` has several `Entity` fields; mark the target with #[enum_event(target)]"),
+                    ));
+                    return None;
+                }
+            }
+        }
+        Fields::Unnamed(_) => marked_or_named,
+    };
+    for (_, extra) in candidates.iter().skip(1) {
+        errors.push(syn::Error::new(
+            extra.span(),
+            format!("variant `{variant_ident}` has multiple event target fields; only one field can be the target"),
+        ));
+    }
+    candidates.first().map(|(index, _)| *index)
+}
+
+/// Returns whether `field` is an `entity` field, the default target of entity variants.
+fn is_entity_field(field: &syn::Field) -> bool {
+    field.ident.as_ref().is_some_and(|id| id == "entity")
+}
 
+/// Returns whether `ty` is written as `Entity` (or a path ending in `Entity`).
+fn is_entity_type(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(type_path) => {
+            type_path.qself.is_none()
+                && type_path
+                    .path
+                    .segments
+                    .last()
+                    .is_some_and(|segment| segment.ident == "Entity" && segment.arguments.is_none())
+        }
+        syn::Type::Group(group) => is_entity_type(&group.elem),
+        syn::Type::Paren(paren) => is_entity_type(&paren.elem),
+        _ => false,
+    }
+}
+
+/// Reports every deref-marked field after the first, since only one field can be dereferenced.
+fn push_multiple_deref_errors(
+    errors: &mut Errors,
+    variant: &syn::Variant,
+    field_attr_infos: &[FieldAttrInfo],
+) {
+    let variant_ident = &variant.ident;
+    for (field, _) in variant
+        .fields
+        .iter()
+        .zip(field_attr_infos)
+        .filter(|(_, info)| info.has_deref)
+        .skip(1)
+    {
+        errors.push(syn::Error::new(
+            field.span(),
+            format!("variant `{variant_ident}` has multiple fields marked for deref; only one field can be dereferenced"),
+        ));
+    }
+}
+
+/// Reports generated structs named like the auxiliary item `ident`.
+fn push_name_conflict_errors(
+    errors: &mut Errors,
+    conversions: &[VariantConversion],
+    ident: &syn::Ident,
+    item: &str,
+) {
+    for conversion in conversions
+        .iter()
+        .filter(|conversion| conversion.struct_ident == *ident)
+    {
+        errors.push(syn::Error::new(
+            conversion.struct_ident.span(),
+            format!("generated struct `{ident}` conflicts with {item}; rename the variant"),
+        ));
+    }
+}
+
+/// Options set with `#[enum_event(...)]` on the enum itself.
+struct EnumAttrInfo {
+    /// `propagate` or `propagate = <relationship>`, applying to every entity variant.
+    propagate_value: Option<proc_macro2::TokenStream>,
+    propagate_span: Option<proc_macro2::Span>,
+    has_auto_propagate: bool,
+    has_any: bool,
+    fan_out_span: Option<proc_macro2::Span>,
+    fan_in_span: Option<proc_macro2::Span>,
+    /// `None` keeps the default snake_case module, `Some(None)` places items next to the enum.
+    module_override: Option<Option<syn::Ident>>,
+    vis_override: Option<syn::Visibility>,
+    name_prefix: String,
+    name_suffix: String,
+    extra_derives: Vec<syn::Path>,
+    no_derives: HashSet<String>,
+    /// Fields injected into every generated struct, e.g. `fields(entity: Entity)`.
+    shared_fields: Vec<syn::Field>,
+    /// `deref = true/false`, overriding the `deref` feature for this enum.
+    deref_override: Option<bool>,
+    /// `deref_mut = false`, making the automatic single-field deref read-only.
+    deref_mut_override: Option<bool>,
+    /// Span of `deref_payload`, which derefs entity events to their one field besides the target.
+    deref_payload_span: Option<proc_macro2::Span>,
+    emits_event: bool,
+    emits_message: bool,
+}
+
+/// Parses the enum's `#[enum_event(...)]` attributes, reporting options that have no effect.
+fn analyze_enum_attrs(attrs: &[Attribute], kind: DeriveKind, errors: &mut Errors) -> EnumAttrInfo {
+    let is_entity_event = kind == DeriveKind::EntityEvent;
+
+    // Check for propagate and auto_propagate attributes on the enum
+    // Can be: #[enum_event(propagate)]
+    //         #[enum_event(propagate = &'static RelType)]
+    //         #[enum_event(auto_propagate, propagate = &'static RelType)]
+    let mut propagate_value: Option<proc_macro2::TokenStream> = None;
+    let mut has_auto_propagate = false;
+    let mut has_any = false;
+    let mut fan_out_span: Option<proc_macro2::Span> = None;
+    let mut fan_in_span: Option<proc_macro2::Span> = None;
+    let mut module_override: Option<Option<syn::Ident>> = None;
+    let mut flatten_span: Option<proc_macro2::Span> = None;
+    let mut vis_override: Option<syn::Visibility> = None;
+    let mut name_prefix = String::new();
+    let mut name_suffix = String::new();
+    let mut extra_derives: Vec<syn::Path> = Vec::new();
+    let mut no_derives: HashSet<String> = HashSet::new();
+    let mut shared_fields: Vec<syn::Field> = Vec::new();
+    let mut deref_override: Option<bool> = None;
+    let mut deref_mut_override: Option<bool> = None;
+    let mut deref_payload_span = None;
+    // `event`/`message` add the other trait on top of the one implied by the derive
+    let mut emits_event = kind != DeriveKind::Message;
+    let mut emits_message = kind == DeriveKind::Message;
+    let mut event_span = None;
+    let mut message_span = None;
+
+    let mut keys = KeyChecker::new(AttrLevel::Enum);
+    let mut propagate_span = None;
+    let mut auto_propagate_span = None;
     for attr in attrs {
         if path_ends_with_ident(attr.path(), "enum_event") {
+            attr.parse_nested_meta(|meta| {
+                keys.check(&meta)?;
+                if path_ends_with_ident(&meta.path, "auto_propagate") {
+                    has_auto_propagate = true;
+                    auto_propagate_span = Some(meta.path.span());
+                    Ok(())
+                } else if path_ends_with_ident(&meta.path, "any") {
+                    has_any = true;
+                    Ok(())
+                } else if path_ends_with_ident(&meta.path, "fan_out") {
+                    fan_out_span = Some(meta.path.span());
+                    Ok(())
+                } else if path_ends_with_ident(&meta.path, "fan_in") {
+                    fan_in_span = Some(meta.path.span());
+                    Ok(())
+                } else if path_ends_with_ident(&meta.path, "module") {
+                    let value = meta.value()?;
+                    if value.peek(syn::Token![self]) {
+                        value.parse::<syn::Token![self]>()?;
+                        module_override = Some(None);
+                    } else {
+                        let name: syn::LitStr = value.parse()?;
+                        let ident = name.parse::<syn::Ident>().map_err(|_| {
+                            syn::Error::new(
+                                name.span(),
+                                "module must be a single identifier such as \"events\"; declare the enum inside a parent module to nest it further",
+                            )
+                        })?;
+                        module_override = Some(Some(ident));
+                    }
+                    Ok(())
+                } else if path_ends_with_ident(&meta.path, "flatten") {
+                    flatten_span = Some(meta.path.span());
+                    Ok(())
+                } else if path_ends_with_ident(&meta.path, "fields") {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    shared_fields.extend(
+                        content.parse_terminated(syn::Field::parse_named, syn::Token![,])?,
+                    );
+                    Ok(())
+                } else if path_ends_with_ident(&meta.path, "derive") {
+                    extra_derives.extend(parse_derive_list(&meta)?);
+                    Ok(())
+                } else if path_ends_with_ident(&meta.path, "no_derive") {
+                    no_derives.extend(parse_no_derive_list(&meta)?);
+                    Ok(())
+                } else if path_ends_with_ident(&meta.path, "deref") {
+                    deref_override = Some(parse_bool_value(&meta)?);
+                    Ok(())
+                } else if path_ends_with_ident(&meta.path, "deref_mut") {
+                    deref_mut_override = Some(parse_bool_value(&meta)?);
+                    Ok(())
+                } else if path_ends_with_ident(&meta.path, "deref_payload") {
+                    deref_payload_span = Some(meta.path.span());
+                    Ok(())
+                } else if path_ends_with_ident(&meta.path, "vis") {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    vis_override = Some(value.parse()?);
+                    Ok(())
+                } else if path_ends_with_ident(&meta.path, "prefix")
+                    || path_ends_with_ident(&meta.path, "suffix")
+                {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    let affix = value.value();
+                    let is_prefix = path_ends_with_ident(&meta.path, "prefix");
+                    // Checked around a placeholder variant name, so a prefix cannot start with a digit
+                    let sample = if is_prefix {
+                        format!("{affix}Variant")
+                    } else {
+                        format!("Variant{affix}")
+                    };
+                    if syn::parse_str::<syn::Ident>(&sample).is_err() {
+                        return Err(syn::Error::new(
+                            value.span(),
+                            format!("`{affix}` would not form valid struct names; prefix and suffix may only contain identifier characters, and a prefix cannot start with a digit"),
+                        ));
+                    }
+                    if is_prefix {
+                        name_prefix = affix;
+                    } else {
+                        name_suffix = affix;
+                    }
+                    Ok(())
+                } else if path_ends_with_ident(&meta.path, "event") {
+                    event_span = Some(meta.path.span());
+                    emits_event = true;
+                    Ok(())
+                } else if path_ends_with_ident(&meta.path, "message") {
+                    message_span = Some(meta.path.span());
+                    emits_message = true;
+                    Ok(())
+                } else if path_ends_with_ident(&meta.path, "propagate") {
+                    propagate_span = Some(meta.path.span());
+                    if meta.input.peek(syn::Token![=]) {
+                        // Parse: propagate = <relationship type>
+                        let ty: syn::Type = meta.value()?.parse().map_err(|err| {
+                            syn::Error::new(err.span(), "expected a relationship type such as `&'static ChildOf` after `propagate =`")
+                        })?;
+                        propagate_value = Some(quote! { #ty });
+                    } else {
+                        // Just: propagate (no value, uses default)
+                        propagate_value = Some(quote! {});
+                    }
+                    Ok(())
+                } else {
+                    unreachable!("validated by KeyChecker")
+                }
+            })
+            .unwrap_or_else(|err| errors.push(err));
+        }
+    }
+    if let Some(span) = auto_propagate_span {
+        if propagate_value.is_none() {
+            errors.push(syn::Error::new(
+                span,
+                "`auto_propagate` has no effect without `propagate` on the enum",
+            ));
+        }
+    }
+    // `event, message` reads the same on every derive, but either key alone may be redundant
+    match (event_span, message_span) {
+        (Some(span), None) if kind != DeriveKind::Message => {
+            let message = if is_entity_event {
+                "`event` has no effect: `EnumEntityEvent` already derives `EntityEvent`"
+            } else {
+                "`event` has no effect: `EnumEvent` already derives `Event`"
+            };
+            errors.push(syn::Error::new(span, message));
+        }
+        (None, Some(span)) if kind == DeriveKind::Message => {
+            errors.push(syn::Error::new(
+                span,
+                "`message` has no effect: `EnumMessage` already derives `Message`",
+            ));
+        }
+        _ => {}
+    }
+    // `flatten` is shorthand for `module = self`
+    if let Some(span) = flatten_span {
+        if matches!(module_override, Some(Some(_))) {
+            errors.push(syn::Error::new(
+                span,
+                "`flatten` cannot be combined with `module = \"...\"`",
+            ));
+        }
+        module_override = Some(None);
+    }
+
+    EnumAttrInfo {
+        propagate_value,
+        propagate_span,
+        has_auto_propagate,
+        has_any,
+        fan_out_span,
+        fan_in_span,
+        module_override,
+        vis_override,
+        name_prefix,
+        name_suffix,
+        extra_derives,
+        no_derives,
+        shared_fields,
+        deref_override,
+        deref_mut_override,
+        deref_payload_span,
+        emits_event,
+        emits_message,
+    }
+}
+
+fn analyze_variant_attrs(attrs: &[Attribute], errors: &mut Errors) -> VariantAttrInfo {
+    let mut info = VariantAttrInfo::default();
+    let mut keys = KeyChecker::new(AttrLevel::Variant);
+    let mut skip_span = None;
+    let mut auto_propagate_span = None;
+
+    for attr in attrs {
+        if is_forwarded_attr(attr) {
+            info.forwarded_attrs.push(attr.clone());
+            if attr.path().is_ident("cfg") {
+                info.cfg_attrs.push(attr.clone());
+            }
+            if attr.path().is_ident("doc") {
+                info.has_docs = true;
+            }
+        } else if path_ends_with_ident(attr.path(), "enum_event") {
             if let Err(err) = attr.parse_nested_meta(|meta| {
+                keys.check(&meta)?;
                 if path_ends_with_ident(&meta.path, "auto_propagate") {
                     info.has_auto_propagate = true;
+                    auto_propagate_span = Some(meta.path.span());
+                    Ok(())
+                } else if path_ends_with_ident(&meta.path, "skip") {
+                    info.skip = true;
+                    skip_span = Some(meta.path.span());
+                    Ok(())
+                } else if path_ends_with_ident(&meta.path, "rename") {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    info.rename = Some(value.parse()?);
+                    Ok(())
+                } else if path_ends_with_ident(&meta.path, "global") {
+                    info.global_span = Some(meta.path.span());
+                    Ok(())
+                } else if path_ends_with_ident(&meta.path, "entity") {
+                    info.entity_span = Some(meta.path.span());
+                    Ok(())
+                } else if path_ends_with_ident(&meta.path, "deref") {
+                    info.deref = Some(parse_bool_value(&meta)?);
+                    Ok(())
+                } else if path_ends_with_ident(&meta.path, "deref_mut") {
+                    info.deref_mut = Some(parse_bool_value(&meta)?);
+                    Ok(())
+                } else if path_ends_with_ident(&meta.path, "default") {
+                    info.default_span = Some(meta.path.span());
+                    Ok(())
+                } else if path_ends_with_ident(&meta.path, "derive") {
+                    info.derives.extend(parse_derive_list(&meta)?);
+                    Ok(())
+                } else if path_ends_with_ident(&meta.path, "no_derive") {
+                    info.no_derives.extend(parse_no_derive_list(&meta)?);
                     Ok(())
                 } else if path_ends_with_ident(&meta.path, "propagate") {
                     if meta.input.peek(syn::Token![=]) {
-                        // Parse: propagate = <value>
-                        meta.input.parse::<syn::Token![=]>()?;
-                        let tokens: proc_macro2::TokenStream = meta.input.parse()?;
-                        info.propagate_value = Some(tokens);
+                        // Parse: propagate = <relationship type>
+                        let ty: syn::Type = meta.value()?.parse().map_err(|err| {
+                            syn::Error::new(err.span(), "expected a relationship type such as `&'static ChildOf` after `propagate =`")
+                        })?;
+                        info.propagate_value = Some(quote! { #ty });
                     } else {
                         // Just: propagate (no value, uses default)
                         info.propagate_value = Some(quote! {});
                     }
                     Ok(())
                 } else {
-                    // Unknown attributes on variants are just ignored (could be other macro's attributes)
-                    Ok(())
+                    unreachable!("validated by KeyChecker")
                 }
             }) {
-                panic!("EnumEvent: failed to parse variant #[enum_event(...)] attribute: {err}");
+                errors.push(err);
             }
         }
     }
 
+    if let Some(span) = auto_propagate_span {
+        if info.propagate_value.is_none() {
+            errors.push(syn::Error::new(
+                span,
+                "`auto_propagate` has no effect without `propagate` on the same variant",
+            ));
+        }
+    }
+    if let (Some(_), Some(span)) = (info.global_span, info.entity_span) {
+        errors.push(syn::Error::new(
+            span,
+            "a variant cannot be both `global` and `entity`",
+        ));
+    }
+    if let Some(span) = skip_span {
+        let configured = info.rename.is_some()
+            || !info.derives.is_empty()
+            || !info.no_derives.is_empty()
+            || info.propagate_value.is_some()
+            || info.global_span.is_some()
+            || info.entity_span.is_some()
+            || info.deref.is_some()
+            || info.deref_mut.is_some()
+            || info.default_span.is_some();
+        if configured {
+            errors.push(syn::Error::new(
+                span,
+                "skipped variants generate no struct, so other enum_event keys have no effect",
+            ));
+        }
+    }
+
     info
 }
 
+/// Lists the Bevy traits derived by a generated struct.
+fn derive_traits(
+    is_entity_event: bool,
+    emits_event: bool,
+    emits_message: bool,
+) -> proc_macro2::TokenStream {
+    let event = if is_entity_event {
+        Some(quote! { ::bevy::prelude::EntityEvent })
+    } else if emits_event {
+        Some(quote! { ::bevy::prelude::Event })
+    } else {
+        None
+    };
+    let message = emits_message.then(|| quote! { ::bevy::prelude::Message });
+    let traits = event.into_iter().chain(message);
+    quote! { #(#traits),* }
+}
+
+/// Prefixes relative paths with `super::` so they resolve from inside the generated module.
+fn adjust_propagate_type_for_module(ty: &mut syn::Type) {
+    fn adjust_path(path: &mut syn::TypePath) {
+        if path.path.leading_colon.is_some() {
+            return;
+        }
+
+        if let Some(first) = path.path.segments.first_mut() {
+            if first.ident == "crate" {
+                return;
+            }
+            // `self::` named the enum's module, which is `super::` from inside the generated one
+            if first.ident == "self" {
+                first.ident = syn::Ident::new("super", first.ident.span());
+                return;
+            }
+        }
+
+        path.path.segments.insert(0, syn::parse_quote!(super));
+    }
+
+    match ty {
+        syn::Type::Reference(ref mut reference) => {
+            adjust_propagate_type_for_module(&mut reference.elem);
+        }
+        syn::Type::Path(ref mut type_path) => adjust_path(type_path),
+        _ => {}
+    }
+}
+
+/// How a generated struct declares its fields.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum StructKind {
+    Unit,
+    Tuple,
+    Named,
+}
+
+/// A field of a generated struct, as seen by its constructors.
+struct StructField {
+    /// The field's name, or the argument name `__argN` for tuple fields.
+    ident: syn::Ident,
+    /// The field's type as written from inside the generated module.
+    ty: syn::Type,
+    /// The field's default value as written from inside the generated module.
+    default: Option<proc_macro2::TokenStream>,
+}
+
+/// A generated struct, as needed to emit its constructors.
+struct StructShape<'a> {
+    ident: &'a syn::Ident,
+    /// The enum's generics as written from inside the generated module.
+    generics: &'a syn::Generics,
+    /// The variant's `cfg` attributes, repeated on every emitted impl.
+    cfg_attrs: &'a [Attribute],
+    kind: StructKind,
+    /// Every field, shared fields first.
+    fields: Vec<StructField>,
+    has_shared_fields: bool,
+    /// Whether a `PhantomData` marker holds the generics no field uses.
+    has_phantom: bool,
+}
+
+impl StructShape<'_> {
+    /// Builds the struct named by `path` from one value per field, filling in the phantom marker.
+    fn construct(
+        &self,
+        path: &proc_macro2::TokenStream,
+        values: impl IntoIterator<Item = proc_macro2::TokenStream>,
+    ) -> proc_macro2::TokenStream {
+        let values = values.into_iter();
+        let phantom = self
+            .has_phantom
+            .then(|| quote! { ::core::marker::PhantomData });
+        match self.kind {
+            StructKind::Tuple => quote! { #path(#(#values,)* #phantom) },
+            StructKind::Unit if phantom.is_none() => quote! { #path },
+            StructKind::Unit | StructKind::Named => {
+                let names = self.fields.iter().map(|field| &field.ident);
+                let phantom = phantom.map(|phantom| quote! { _phantom: #phantom, });
+                quote! { #path { #(#names: #values,)* #phantom } }
+            }
+        }
+    }
+
+    /// Implements `new`, taking every field without a default value in order.
+    fn new_impl(&self) -> proc_macro2::TokenStream {
+        let params = self
+            .fields
+            .iter()
+            .filter(|field| field.default.is_none())
+            .map(|StructField { ident, ty, .. }| quote! { #ident: #ty });
+        let values = self.fields.iter().map(|field| match &field.default {
+            Some(value) => value.clone(),
+            None => {
+                let ident = &field.ident;
+                quote! { #ident }
+            }
+        });
+        let value = self.construct(&quote! { Self }, values);
+        let doc = if self.fields.is_empty() {
+            if self.has_phantom {
+                "Creates the event, filling in the phantom marker."
+            } else {
+                "Creates the event."
+            }
+        } else if self.fields.iter().any(|field| field.default.is_some()) {
+            "Creates the event from its fields, leaving out those with a default."
+        } else if self.has_phantom {
+            "Creates the event from its fields, filling in the phantom marker."
+        } else if self.has_shared_fields {
+            "Creates the event from the shared fields followed by the variant's fields."
+        } else {
+            "Creates the event from its fields."
+        };
+        // Without fields to move, `new` can be used in constants
+        let constness = self.fields.is_empty().then(|| quote! { const });
+        let struct_ident = self.ident;
+        let cfg_attrs = self.cfg_attrs;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        quote! {
+            #(#cfg_attrs)*
+            #[allow(deprecated)]
+            impl #impl_generics #struct_ident #ty_generics #where_clause {
+                #[doc = #doc]
+                #[inline]
+                pub #constness fn new(#(#params),*) -> Self {
+                    #value
+                }
+            }
+        }
+    }
+
+    /// Implements `Default`, requiring it of every field without a default value.
+    fn default_impl(&self) -> proc_macro2::TokenStream {
+        let mut default_generics = self.generics.clone();
+        let default_where_clause = default_generics.make_where_clause();
+        let values: Vec<_> = self
+            .fields
+            .iter()
+            .map(|field| match &field.default {
+                Some(value) => value.clone(),
+                None => {
+                    let ty = &field.ty;
+                    default_where_clause
+                        .predicates
+                        .push(syn::parse_quote! { #ty: ::core::default::Default });
+                    quote! { ::core::default::Default::default() }
+                }
+            })
+            .collect();
+        let value = self.construct(&quote! { Self }, values);
+        let struct_ident = self.ident;
+        let cfg_attrs = self.cfg_attrs;
+        let (impl_generics, ty_generics, where_clause) = default_generics.split_for_impl();
+        quote! {
+            #(#cfg_attrs)*
+            #[allow(deprecated)]
+            impl #impl_generics ::core::default::Default for #struct_ident #ty_generics #where_clause {
+                #[inline]
+                fn default() -> Self {
+                    #value
+                }
+            }
+        }
+    }
+}
+
+/// Structs with named fields, including unit variants receiving shared fields, get a builder,
+/// unless a field named `build` would collide with the builder's terminal method.
+fn has_builder(fields: &Fields, shared_fields: &[syn::Field]) -> bool {
+    let named = match fields {
+        Fields::Named(_) => true,
+        Fields::Unit => !shared_fields.is_empty(),
+        Fields::Unnamed(_) => false,
+    };
+    named
+        && !shared_fields
+            .iter()
+            .chain(fields)
+            .any(|field| field.ident.as_ref().is_some_and(|ident| ident == "build"))
+}
+
+/// Generates the builder of a struct with named fields.
+///
+/// The builder tracks each field without a default in a type parameter, `()` until set, so
+/// `build` only exists once they are all set; defaulted fields are `Option`s.
+fn builder_items(
+    shape: &StructShape,
+    item_vis: &proc_macro2::TokenStream,
+    forwarded_attrs: &[Attribute],
+) -> proc_macro2::TokenStream {
+    let struct_ident = shape.ident;
+    let builder_ident = syn::Ident::new(&format!("{struct_ident}Builder"), struct_ident.span());
+    let generics = shape.generics;
+    let cfg_attrs = shape.cfg_attrs;
+    let state_params: Vec<Option<syn::Ident>> = shape
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            field
+                .default
+                .is_none()
+                .then(|| syn::Ident::new(&format!("__Field{index}"), struct_ident.span()))
+        })
+        .collect();
+    // Parameters after one with a default need a default too
+    let needs_state_default = generics.params.iter().any(|param| match param {
+        syn::GenericParam::Type(param) => param.default.is_some(),
+        syn::GenericParam::Const(param) => param.default.is_some(),
+        syn::GenericParam::Lifetime(_) => false,
+    });
+    let mut builder_generics = generics.clone();
+    for param in state_params.iter().flatten() {
+        builder_generics.params.push(if needs_state_default {
+            syn::parse_quote! { #param = () }
+        } else {
+            syn::parse_quote! { #param }
+        });
+    }
+    let generic_args: Vec<_> = generics
+        .params
+        .iter()
+        .map(|param| match param {
+            syn::GenericParam::Lifetime(param) => {
+                let lifetime = &param.lifetime;
+                quote! { #lifetime }
+            }
+            syn::GenericParam::Type(param) => {
+                let ident = &param.ident;
+                quote! { #ident }
+            }
+            syn::GenericParam::Const(param) => {
+                let ident = &param.ident;
+                quote! { #ident }
+            }
+        })
+        .collect();
+    // The builder type with the given state for each field without a default
+    let builder_type = |states: &[proc_macro2::TokenStream]| {
+        let args = generic_args.iter().chain(states);
+        quote! { #builder_ident<#(#args),*> }
+    };
+    let unset_states: Vec<_> = state_params
+        .iter()
+        .flatten()
+        .map(|_| quote! { () })
+        .collect();
+    let set_states: Vec<_> = shape
+        .fields
+        .iter()
+        .filter(|field| field.default.is_none())
+        .map(|field| {
+            let ty = &field.ty;
+            quote! { #ty }
+        })
+        .collect();
+    let unset_builder = builder_type(&unset_states);
+    let set_builder = builder_type(&set_states);
+    let builder_fields =
+        shape
+            .fields
+            .iter()
+            .zip(&state_params)
+            .map(|(StructField { ident, ty, .. }, state)| match state {
+                Some(state) => quote! { #ident: #state, },
+                None => quote! { #ident: ::core::option::Option<#ty>, },
+            });
+    // Generics used only by required fields are not named by the builder's fields
+    let phantom_params: Vec<_> = generics
+        .type_params()
+        .map(|param| {
+            let ident = &param.ident;
+            quote! { #ident }
+        })
+        .chain(generics.lifetimes().map(|param| {
+            let lifetime = &param.lifetime;
+            quote! { &#lifetime () }
+        }))
+        .collect();
+    let (builder_phantom, builder_phantom_init) = if phantom_params.is_empty() {
+        (None, None)
+    } else {
+        (
+            Some(quote! {
+                _phantom: ::core::marker::PhantomData<(#(#phantom_params ,)*)>,
+            }),
+            Some(quote! { _phantom: ::core::marker::PhantomData, }),
+        )
+    };
+    let empty_fields = shape
+        .fields
+        .iter()
+        .zip(&state_params)
+        .map(|(field, state)| {
+            let ident = &field.ident;
+            match state {
+                Some(_) => quote! { #ident: () },
+                None => quote! { #ident: ::core::option::Option::None },
+            }
+        });
+    let state_idents: Vec<_> = state_params
+        .iter()
+        .flatten()
+        .map(|state| quote! { #state })
+        .collect();
+    let mut state_index = 0;
+    let setters: Vec<_> = shape
+        .fields
+        .iter()
+        .zip(&state_params)
+        .map(|(StructField { ident, ty, .. }, state)| {
+            let doc = format!("Sets `{ident}`.");
+            if state.is_none() {
+                return quote! {
+                    #[doc = #doc]
+                    #[inline]
+                    pub fn #ident(mut self, value: #ty) -> Self {
+                        self.#ident = ::core::option::Option::Some(value);
+                        self
+                    }
+                };
+            }
+            let mut states = state_idents.clone();
+            states[state_index] = quote! { #ty };
+            state_index += 1;
+            let next_builder = builder_type(&states);
+            let moved_fields = shape
+                .fields
+                .iter()
+                .map(|other| &other.ident)
+                .filter(|other| *other != ident)
+                .map(|other| quote! { #other: self.#other, });
+            quote! {
+                #[doc = #doc]
+                #[inline]
+                pub fn #ident(self, value: #ty) -> #next_builder {
+                    #builder_ident {
+                        #ident: value,
+                        #(#moved_fields)*
+                        #builder_phantom_init
+                    }
+                }
+            }
+        })
+        .collect();
+    let build_values = shape.fields.iter().map(|field| {
+        let ident = &field.ident;
+        match &field.default {
+            Some(value) => quote! {
+                match self.#ident {
+                    ::core::option::Option::Some(value) => value,
+                    ::core::option::Option::None => #value,
+                }
+            },
+            None => quote! { self.#ident },
+        }
+    });
+    let built = shape.construct(&quote! { #struct_ident }, build_values);
+    let builder_doc = format!(
+        "Builder for `{struct_ident}`, created with `{struct_ident}::builder`.\n\n`build` becomes available once every field without a default value is set."
+    );
+    // The builder is named after the variant, so it shares the variant's lint levels
+    let builder_lints = forwarded_attrs.iter().filter(|attr| {
+        ["allow", "warn", "deny", "forbid"]
+            .iter()
+            .any(|name| attr.path().is_ident(name))
+    });
+    let where_clause = generics.where_clause.as_ref();
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    let (builder_impl_generics, builder_ty_generics, _) = builder_generics.split_for_impl();
+    quote! {
+        #(#cfg_attrs)*
+        #[doc = #builder_doc]
+        #(#builder_lints)*
+        #[must_use]
+        #[allow(deprecated)]
+        #item_vis struct #builder_ident #builder_generics #where_clause {
+            #(#builder_fields)*
+            #builder_phantom
+        }
+
+        #(#cfg_attrs)*
+        #[allow(deprecated)]
+        impl #impl_generics #struct_ident #ty_generics #where_clause {
+            /// Starts building the event field by field.
+            #[inline]
+            pub fn builder() -> #unset_builder {
+                #builder_ident {
+                    #(#empty_fields,)*
+                    #builder_phantom_init
+                }
+            }
+        }
+
+        #(#cfg_attrs)*
+        #[allow(deprecated)]
+        impl #builder_impl_generics #builder_ident #builder_ty_generics #where_clause {
+            #(#setters)*
+        }
+
+        #(#cfg_attrs)*
+        #[allow(deprecated)]
+        impl #impl_generics #set_builder #where_clause {
+            /// Builds the event, giving unset fields their default value.
+            #[inline]
+            pub fn build(self) -> #struct_ident #ty_generics {
+                #built
+            }
+        }
+    }
+}
+
+/// Everything the enum's `trigger` and `write` methods dispatch on.
+struct Dispatch<'a> {
+    conversions: &'a [VariantConversion],
+    /// Patterns binding skipped variants as `skipped`, which are handed back to the caller.
+    skipped_patterns: &'a [proc_macro2::TokenStream],
+    /// The umbrella `Any` type as named from the enum's scope, with `#[enum_event(any)]`.
+    any_path: Option<proc_macro2::TokenStream>,
+    is_entity_event: bool,
+    emits_event: bool,
+    emits_message: bool,
+    /// Fields injected into every generated struct, which the caller passes to the methods.
+    shared_fields: &'a [syn::Field],
+    /// `Send + Sync + 'static` bounds on the enum's generics, which Bevy requires of events.
+    static_bounds: &'a [proc_macro2::TokenStream],
+}
+
+impl Dispatch<'_> {
+    /// Moves the variant's data into its generated struct and hands it to `sink.dispatch_method`.
+    fn body(
+        &self,
+        sink: &proc_macro2::TokenStream,
+        dispatch_method: &proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let is_entity_any = self.any_path.is_some() && self.is_entity_event;
+        let arms = self.conversions.iter().map(|conversion| {
+            let variant_pattern = &conversion.variant_pattern;
+            let struct_expr = &conversion.struct_expr;
+            let cfg_attrs = &conversion.cfg_attrs;
+            // The entity `Any` event targets the entity of the variant event
+            let target = conversion.target_binding.as_ref().filter(|_| is_entity_any);
+            quote! {
+                #(#cfg_attrs)*
+                #variant_pattern => {
+                    #sink.#dispatch_method(#struct_expr);
+                    #target
+                }
+            }
+        });
+        // Skipped variants are returned before anything is dispatched, the `Any` event included
+        let skipped_arms = self.skipped_patterns.iter().map(|pattern| {
+            quote! { #pattern => return ::core::result::Result::Err(skipped), }
+        });
+        let arms = arms.chain(skipped_arms);
+        let body = match &self.any_path {
+            None => quote! {
+                match self {
+                    #(#arms)*
+                }
+            },
+            Some(any_path) if self.is_entity_event => quote! {
+                let value = ::core::clone::Clone::clone(&self);
+                let entity = match self {
+                    #(#arms)*
+                };
+                #sink.#dispatch_method(#any_path { entity, value });
+            },
+            Some(any_path) => quote! {
+                let any = #any_path(::core::clone::Clone::clone(&self));
+                match self {
+                    #(#arms)*
+                }
+                #sink.#dispatch_method(any);
+            },
+        };
+        let dispatch_ok =
+            (!self.skipped_patterns.is_empty()).then(|| quote! { ::core::result::Result::Ok(()) });
+        quote! {
+            #body
+            #dispatch_ok
+        }
+    }
+
+    /// Generates `trigger`/`trigger_world` for events, `write`/`write_world` for messages, and
+    /// `trigger_and_write`/`trigger_and_write_world` for types that are both.
+    fn methods(&self) -> proc_macro2::TokenStream {
+        let mut trigger_bounds = self.static_bounds.to_vec();
+        if self.any_path.is_some() {
+            trigger_bounds.push(quote! { Self: ::core::clone::Clone });
+        }
+        let trigger_where_clause =
+            (!trigger_bounds.is_empty()).then(|| quote! { where #(#trigger_bounds),* });
+        // Enums with skipped variants hand them back, so callers can tell nothing was dispatched
+        let has_skipped = !self.skipped_patterns.is_empty();
+        let dispatch_output = has_skipped.then(|| quote! { -> ::core::result::Result<(), Self> });
+        let dispatch_ok = has_skipped.then(|| quote! { ::core::result::Result::Ok(()) });
+        let skipped_doc = has_skipped.then(|| {
+            quote! {
+                #[doc = ""]
+                #[doc = "Skipped variants dispatch nothing and are returned as `Err(self)`."]
+            }
+        });
+        // Shared fields are supplied by the caller, after the commands or world
+        let shared_idents: Vec<_> = self
+            .shared_fields
+            .iter()
+            .filter_map(|field| field.ident.as_ref())
+            .collect();
+        let shared_params: Vec<_> = self
+            .shared_fields
+            .iter()
+            .map(|field| {
+                let ident = &field.ident;
+                let ty = &field.ty;
+                quote! { #ident: #ty }
+            })
+            .collect();
+        let shared_args_doc = (!shared_idents.is_empty()).then(|| {
+            let names: Vec<_> = shared_idents
+                .iter()
+                .map(|ident| format!("`{ident}`"))
+                .collect();
+            let doc = format!(
+                "The shared fields {} are passed after the commands or world.",
+                names.join(", ")
+            );
+            quote! {
+                #[doc = ""]
+                #[doc = #doc]
+            }
+        });
+        let commands = quote! { commands };
+        let world = quote! { world };
+        let trigger_methods = self.emits_event.then(|| {
+            let trigger = quote! { trigger };
+            let commands_dispatch_body = self.body(&commands, &trigger);
+            let world_dispatch_body = self.body(&world, &trigger);
+            quote! {
+                /// Triggers the generated event matching this variant via [`Commands`](::bevy::prelude::Commands).
+                ///
+                /// The variant's data is moved into the corresponding generated struct. With
+                /// `#[enum_event(any)]`, the umbrella `Any` event is triggered afterwards.
+                #shared_args_doc
+                #skipped_doc
+                pub fn trigger(self, commands: &mut ::bevy::prelude::Commands #(, #shared_params)*) #dispatch_output #trigger_where_clause {
+                    #commands_dispatch_body
+                }
+
+                /// Triggers the generated event matching this variant directly on a [`World`](::bevy::prelude::World).
+                ///
+                /// The variant's data is moved into the corresponding generated struct. With
+                /// `#[enum_event(any)]`, the umbrella `Any` event is triggered afterwards.
+                #shared_args_doc
+                #skipped_doc
+                pub fn trigger_world(self, world: &mut ::bevy::prelude::World #(, #shared_params)*) #dispatch_output #trigger_where_clause {
+                    #world_dispatch_body
+                }
+            }
+        });
+        let write_methods = self.emits_message.then(|| {
+            let write_message = quote! { write_message };
+            let commands_dispatch_body = self.body(&commands, &write_message);
+            let world_dispatch_body = self.body(&world, &write_message);
+            quote! {
+                /// Writes the generated message matching this variant via [`Commands`](::bevy::prelude::Commands).
+                ///
+                /// The variant's data is moved into the corresponding generated struct. With
+                /// `#[enum_event(any)]`, the umbrella `Any` message is written afterwards.
+                #shared_args_doc
+                #skipped_doc
+                pub fn write(self, commands: &mut ::bevy::prelude::Commands #(, #shared_params)*) #dispatch_output #trigger_where_clause {
+                    #commands_dispatch_body
+                }
+
+                /// Writes the generated message matching this variant directly to a [`World`](::bevy::prelude::World).
+                ///
+                /// The variant's data is moved into the corresponding generated struct. With
+                /// `#[enum_event(any)]`, the umbrella `Any` message is written afterwards.
+                #shared_args_doc
+                #skipped_doc
+                pub fn write_world(self, world: &mut ::bevy::prelude::World #(, #shared_params)*) #dispatch_output #trigger_where_clause {
+                    #world_dispatch_body
+                }
+            }
+        });
+        // Types that are both events and messages can be sent both ways from a single value
+        let trigger_and_write_methods = (self.emits_event && self.emits_message).then(|| {
+            let mut both_bounds = self.static_bounds.to_vec();
+            both_bounds.push(quote! { Self: ::core::clone::Clone });
+            let skipped_try = has_skipped.then(|| quote! { ? });
+            quote! {
+                /// Triggers the generated event matching this variant and writes it as a message,
+                /// both via [`Commands`](::bevy::prelude::Commands).
+                ///
+                /// Observers run when the commands are applied; message readers see the value
+                /// the next time their system runs.
+                #shared_args_doc
+                #skipped_doc
+                pub fn trigger_and_write(self, commands: &mut ::bevy::prelude::Commands #(, #shared_params)*) #dispatch_output where #(#both_bounds),* {
+                    ::core::clone::Clone::clone(&self).trigger(commands #(, ::core::clone::Clone::clone(&#shared_idents))*) #skipped_try;
+                    self.write(commands #(, #shared_idents)*) #skipped_try;
+                    #dispatch_ok
+                }
+
+                /// Triggers the generated event matching this variant on a [`World`](::bevy::prelude::World)
+                /// and writes it as a message to the same world.
+                #shared_args_doc
+                #skipped_doc
+                pub fn trigger_and_write_world(self, world: &mut ::bevy::prelude::World #(, #shared_params)*) #dispatch_output where #(#both_bounds),* {
+                    ::core::clone::Clone::clone(&self).trigger_world(world #(, ::core::clone::Clone::clone(&#shared_idents))*) #skipped_try;
+                    self.write_world(world #(, #shared_idents)*) #skipped_try;
+                    #dispatch_ok
+                }
+            }
+        });
+        quote! {
+            #trigger_methods
+            #write_methods
+            #trigger_and_write_methods
+        }
+    }
+}
+
+/// Translates the enum's visibility into the equivalent visibility for items inside the
+/// generated module, which sits one level below the enum.
+fn visibility_inside_module(vis: &syn::Visibility) -> proc_macro2::TokenStream {
+    match vis {
+        syn::Visibility::Public(_) => quote! { pub },
+        syn::Visibility::Inherited => quote! { pub(super) },
+        syn::Visibility::Restricted(restricted) => {
+            let path = &restricted.path;
+            match path.segments.first() {
+                _ if path.leading_colon.is_some() => quote! { #vis },
+                Some(first) if first.ident == "crate" => quote! { #vis },
+                Some(first) if first.ident == "self" => {
+                    let rest = path.segments.iter().skip(1);
+                    quote! { pub(in super #(::#rest)*) }
+                }
+                _ => quote! { pub(in super::#path) },
+            }
+        }
+    }
+}
+
+/// Builds the `#[entity_event(...)]` attribute for a generated `EntityEvent` struct.
+///
+/// We accept `#[enum_event(propagate)]` on the enum, but generate `#[entity_event(propagate)]`
+/// on the struct because that's what Bevy's `EntityEvent` derive expects.
+fn entity_event_propagate_attr(
+    propagate_value: Option<&proc_macro2::TokenStream>,
+    auto_propagate: bool,
+    in_module: bool,
+) -> proc_macro2::TokenStream {
+    match propagate_value {
+        Some(tokens) if tokens.is_empty() => {
+            if auto_propagate {
+                quote! { #[entity_event(auto_propagate, propagate)] }
+            } else {
+                quote! { #[entity_event(propagate)] }
+            }
+        }
+        Some(tokens) => {
+            let adjusted_tokens = if !in_module {
+                tokens.clone()
+            } else if let Ok(mut ty) = syn::parse2::<syn::Type>(tokens.clone()) {
+                adjust_propagate_type_for_module(&mut ty);
+                quote! { #ty }
+            } else {
+                quote! { #tokens }
+            };
+
+            if auto_propagate {
+                quote! { #[entity_event(auto_propagate, propagate = #adjusted_tokens)] }
+            } else {
+                quote! { #[entity_event(propagate = #adjusted_tokens)] }
+            }
+        }
+        None => quote! {},
+    }
+}
+
 /// Derive macro that generates Bevy `Event` types from enum variants.
 ///
 /// Generates a snake_case module containing one `Event` struct per enum variant.
@@ -399,37 +2373,49 @@ fn analyze_variant_attrs(attrs: &[Attribute]) -> VariantAttrInfo {
 /// }
 /// ```
 ///
-/// Generates:
+/// Generates, abridged:
 ///
-/// ```rust
-/// pub mod action {
-///     use bevy::prelude::Event;
+/// ```rust,ignore
+/// // The enum is private, so its module is too, and the items are visible to the enum's scope
+/// mod action {
+///     #[allow(unused_imports)]
+///     use super::*;
 ///
-///     #[derive(Event, Clone, Debug)]
-///     pub struct Jump;
+///     #[derive(::bevy::prelude::Event, Clone, Copy, Debug, Default)]
+///     pub(super) struct Jump;
 ///
-///     #[derive(Event, Clone, Debug)]
-///     pub struct Run(pub f32);
+///     #[derive(::bevy::prelude::Event, ::bevy::prelude::Deref, ::bevy::prelude::DerefMut, Clone, Debug)]
+///     pub(super) struct Run(#[deref] pub(super) f32);
 ///
-///     #[derive(Event, Clone, Debug)]
-///     pub struct Attack {
-///         pub damage: i32,
-///         pub critical: bool,
+///     #[derive(::bevy::prelude::Event, Clone, Debug)]
+///     pub(super) struct Attack {
+///         pub(super) damage: i32,
+///         pub(super) critical: bool,
 ///     }
+///
+///     // ...plus `new` on every struct and `Attack::builder()`
 /// }
 /// ```
 ///
+/// The enum also gains `trigger(self, &mut Commands)` and `trigger_world(self, &mut World)`
+/// methods that trigger the generated event for the value's variant, and every generated
+/// struct implements `From<Struct> for Enum` and `TryFrom<Enum> for Struct`.
+///
+/// Add `#[enum_event(any)]` to additionally generate an umbrella `Any(Enum)` event that the
+/// `trigger` methods fire alongside every variant event.
+///
 /// # Deref Feature
 ///
 /// When enabled (default), single-field variants automatically implement `Deref`/`DerefMut`.
 /// For multi-field variants, mark one field with `#[enum_event(deref)]`.
 ///
-/// # Panics
+/// # Errors
 ///
-/// Panics if applied to a non-enum type.
+/// Invalid input, such as a non-enum type or a malformed `#[enum_event(...)]` attribute, is
+/// reported as compile errors pointing at the offending item.
 #[proc_macro_derive(EnumEvent, attributes(enum_event, deref, deref_mut))]
 pub fn derive_enum_events(input: TokenStream) -> TokenStream {
-    derive_enum_event_impl(input, false)
+    derive_enum_event_impl(input, DeriveKind::Event)
 }
 
 /// Derive macro that generates Bevy `EntityEvent` types from enum variants.
@@ -438,8 +2424,8 @@ pub fn derive_enum_events(input: TokenStream) -> TokenStream {
 ///
 /// # Requirements
 ///
-/// - **Named fields only**: All variants must use `{ field: Type }` syntax
-/// - **Entity field**: Must have `entity: Entity` or a field marked `#[enum_event(target)]`
+/// - **Entity field**: Named variants must have `entity: Entity` or a field marked
+///   `#[enum_event(target)]`; tuple variants a marked field or a single `Entity` field
 ///
 /// # Example
 ///
@@ -525,96 +2511,256 @@ pub fn derive_enum_events(input: TokenStream) -> TokenStream {
     attributes(enum_event, event_target, deref, deref_mut)
 )]
 pub fn derive_enum_entity_events(input: TokenStream) -> TokenStream {
-    derive_enum_event_impl(input, true)
+    derive_enum_event_impl(input, DeriveKind::EntityEvent)
 }
 
-#[allow(clippy::too_many_lines)]
-fn derive_enum_event_impl(input: TokenStream, is_entity_event: bool) -> TokenStream {
+/// Derive macro that generates Bevy `Message` types from enum variants.
+///
+/// Like `EnumEvent`, but generates buffered messages that are read with `MessageReader`
+/// and written with `MessageWriter`. The generated module also contains an
+/// `add_messages(&mut App)` function that registers every generated message type.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_enum_event::EnumMessage;
+///
+/// #[derive(EnumMessage, Clone)]
+/// enum PlayerState {
+///     Idle,
+///     Running { speed: f32 },
+/// }
+///
+/// fn count_runs(mut runs: MessageReader<player_state::Running>) {
+///     for run in runs.read() {
+///         println!("Running at {}", run.speed);
+///     }
+/// }
+///
+/// let mut app = App::new();
+/// player_state::add_messages(&mut app);
+/// app.add_systems(Update, count_runs);
+/// ```
+///
+/// The enum gains `write(self, &mut Commands)` and `write_world(self, &mut World)` methods that
+/// write the generated message for the value's variant, plus the same `From`/`TryFrom`
+/// conversions as `EnumEvent`.
+#[proc_macro_derive(EnumMessage, attributes(enum_event, deref, deref_mut))]
+pub fn derive_enum_messages(input: TokenStream) -> TokenStream {
+    derive_enum_event_impl(input, DeriveKind::Message)
+}
+
+fn derive_enum_event_impl(input: TokenStream, kind: DeriveKind) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
-    let enum_name = &input.ident;
+    expand_enum_event(&input, kind)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
 
-    // Check for propagate and auto_propagate attributes on the enum
-    // Can be: #[enum_event(propagate)]
-    //         #[enum_event(propagate = &'static RelType)]
-    //         #[enum_event(auto_propagate, propagate = &'static RelType)]
-    let mut propagate_value: Option<proc_macro2::TokenStream> = None;
-    let mut has_auto_propagate = false;
+#[allow(clippy::too_many_lines)]
+fn expand_enum_event(
+    input: &DeriveInput,
+    kind: DeriveKind,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let mut errors = Errors::default();
+    let is_entity_event = kind == DeriveKind::EntityEvent;
+    let enum_name = &input.ident;
 
-    for attr in &input.attrs {
-        if path_ends_with_ident(attr.path(), "enum_event") {
-            attr.parse_nested_meta(|meta| {
-                if path_ends_with_ident(&meta.path, "auto_propagate") {
-                    has_auto_propagate = true;
-                    Ok(())
-                } else if path_ends_with_ident(&meta.path, "propagate") {
-                    if meta.input.peek(syn::Token![=]) {
-                        // Parse: propagate = <value>
-                        // Capture the remaining tokens as-is without parsing
-                        meta.input.parse::<syn::Token![=]>()?;
-                        // Parse the rest of the input as raw tokens
-                        let tokens: proc_macro2::TokenStream = meta.input.parse()?;
-                        propagate_value = Some(tokens);
-                    } else {
-                        // Just: propagate (no value, uses default)
-                        propagate_value = Some(quote! {});
-                    }
-                    Ok(())
-                } else {
-                    Err(meta.error("unknown enum_event attribute"))
-                }
-            })
-            .unwrap_or_else(|e| panic!("Failed to parse enum_event attribute: {e}"));
-        }
-    }
+    let EnumAttrInfo {
+        propagate_value,
+        propagate_span,
+        has_auto_propagate,
+        has_any,
+        fan_out_span,
+        fan_in_span,
+        module_override,
+        vis_override,
+        name_prefix,
+        name_suffix,
+        extra_derives,
+        no_derives,
+        shared_fields,
+        deref_override,
+        deref_mut_override,
+        deref_payload_span,
+        emits_event,
+        emits_message,
+    } = analyze_enum_attrs(&input.attrs, kind, &mut errors);
+    let has_fan_out = fan_out_span.is_some();
+    let has_fan_in = fan_in_span.is_some();
 
     // Extract variants from enum
     let variants = match &input.data {
         Data::Enum(data_enum) => &data_enum.variants,
-        _ => panic!("EnumEvent can only be derived for enums"),
+        Data::Struct(data) => {
+            return Err(syn::Error::new(
+                data.struct_token.span,
+                "enum_event derives can only be used on enums",
+            ))
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span,
+                "enum_event derives can only be used on enums",
+            ))
+        }
     };
 
-    // Convert EnumName to snake_case for module name
-    let module_name_str = to_snake_case(&enum_name.to_string());
-    let module_name = syn::Ident::new(&module_name_str, enum_name.span());
-
-    #[allow(clippy::items_after_statements)]
-    fn adjust_propagate_type_for_module(ty: &mut syn::Type) {
-        fn adjust_path(path: &mut syn::TypePath) {
-            if path.path.leading_colon.is_some() {
-                return;
-            }
-
-            if let Some(first) = path.path.segments.first() {
-                let ident = &first.ident;
-                let starts_with_crate = ident == "crate";
-                let starts_with_super = ident == "super";
-                let starts_with_self = ident == "self";
-
-                if starts_with_crate || starts_with_super || starts_with_self {
-                    return;
-                }
-            }
-
-            path.path.segments.insert(0, syn::parse_quote!(super));
+    // Convert EnumName to snake_case for module name, unless overridden
+    let enum_snake_case = to_snake_case(&enum_name.to_string());
+    let module_name = module_override
+        .unwrap_or_else(|| Some(syn::Ident::new(&enum_snake_case, enum_name.span())));
+    let is_flat = module_name.is_none();
+    // Path prefix naming generated items from the enum's scope
+    let module_prefix = module_name.as_ref().map(|module| quote! { #module:: });
+    // Path naming the enum from the generated items' scope
+    let (enum_path, enum_doc_path) = if is_flat {
+        (quote! { #enum_name }, enum_name.to_string())
+    } else {
+        (quote! { super::#enum_name }, format!("super::{enum_name}"))
+    };
+    // Bevy derives are named by full path so they never clash with names in the enum's scope
+    let deref_derive = |with_mut: bool| {
+        if with_mut {
+            quote! { ::bevy::prelude::Deref, ::bevy::prelude::DerefMut }
+        } else {
+            quote! { ::bevy::prelude::Deref }
+        }
+    };
+    // Generated items follow the enum's visibility unless overridden with `vis = "..."`
+    let vis = vis_override.as_ref().unwrap_or(&input.vis);
+    let item_vis = if is_flat {
+        quote! { #vis }
+    } else {
+        visibility_inside_module(vis)
+    };
+    // Flat items share the enum's scope with other enums, so auxiliary names get the enum's name
+    let aux_ident = |name: &str| {
+        if is_flat {
+            syn::Ident::new(&format!("{enum_name}{name}"), enum_name.span())
+        } else {
+            syn::Ident::new(name, enum_name.span())
         }
+    };
+    let aux_fn_ident = |name: &str| {
+        if is_flat {
+            syn::Ident::new(&format!("{enum_snake_case}_{name}"), enum_name.span())
+        } else {
+            syn::Ident::new(name, enum_name.span())
+        }
+    };
 
-        match ty {
-            syn::Type::Reference(ref mut reference) => {
-                adjust_propagate_type_for_module(&mut reference.elem);
-            }
-            syn::Type::Path(ref mut type_path) => adjust_path(type_path),
-            _ => {}
+    // Generated struct names: `rename` wins, otherwise the variant name with the enum's affixes
+    let variant_infos: Vec<(VariantAttrInfo, syn::Ident)> = variants
+        .iter()
+        .map(|variant| {
+            let info = analyze_variant_attrs(&variant.attrs, &mut errors);
+            let struct_ident = info.rename.clone().unwrap_or_else(|| {
+                syn::Ident::new(
+                    &format!("{name_prefix}{}{name_suffix}", variant.ident),
+                    variant.ident.span(),
+                )
+            });
+            (info, struct_ident)
+        })
+        .collect();
+    let mut seen_struct_names = HashSet::new();
+    for (_, struct_ident) in variant_infos.iter().filter(|(info, _)| !info.skip) {
+        if !seen_struct_names.insert(struct_ident.to_string()) {
+            errors.push(syn::Error::new(
+                struct_ident.span(),
+                format!("more than one variant generates a struct named `{struct_ident}`"),
+            ));
+        }
+        if is_flat && struct_ident == enum_name {
+            errors.push(syn::Error::new(
+                struct_ident.span(),
+                format!("flattened struct `{struct_ident}` conflicts with the enum; add a prefix, suffix or rename"),
+            ));
+        }
+    }
+    let builder_idents: Vec<syn::Ident> = variants
+        .iter()
+        .zip(&variant_infos)
+        .filter(|(variant, (info, _))| !info.skip && has_builder(&variant.fields, &shared_fields))
+        .map(|(_, (_, struct_ident))| {
+            syn::Ident::new(&format!("{struct_ident}Builder"), struct_ident.span())
+        })
+        .collect();
+    for builder_ident in &builder_idents {
+        if seen_struct_names.contains(&builder_ident.to_string()) {
+            errors.push(syn::Error::new(
+                builder_ident.span(),
+                format!("generated struct `{builder_ident}` conflicts with a generated builder; rename the variant"),
+            ));
         }
     }
 
     let generics = input.generics.clone();
-    let struct_generics = if generics.params.is_empty() {
+    let generic_param_names: Vec<String> = generics
+        .type_params()
+        .map(|param| param.ident.to_string())
+        .chain(generics.const_params().map(|param| param.ident.to_string()))
+        .collect();
+    // The generated module glob-imports the enum's scope, but its own items shadow that import
+    let shadowed_names: HashSet<String> = if is_flat {
+        HashSet::new()
+    } else {
+        variant_infos
+            .iter()
+            .filter(|(info, _)| !info.skip)
+            .map(|(_, struct_ident)| struct_ident.to_string())
+            .chain(builder_idents.iter().map(ToString::to_string))
+            .chain(
+                ["Any", "FanOutPlugin", "FanInPlugin"]
+                    .iter()
+                    .map(|name| (*name).to_string()),
+            )
+            .collect()
+    };
+    let mut shadowed_path_rewriter = ShadowedPathRewriter {
+        shadowed_names: &shadowed_names,
+        generic_param_names: &generic_param_names,
+        in_module: !is_flat,
+    };
+    // Generics as written from inside the generated module
+    let mut module_generics = generics.clone();
+    shadowed_path_rewriter.visit_generics_mut(&mut module_generics);
+    let scoped_type = |ty: &syn::Type| {
+        let mut ty = ty.clone();
+        ShadowedPathRewriter {
+            shadowed_names: &shadowed_names,
+            generic_param_names: &generic_param_names,
+            in_module: !is_flat,
+        }
+        .visit_type_mut(&mut ty);
+        ty
+    };
+    // A field's default value as written from inside the generated module
+    let scoped_default = |info: &FieldAttrInfo| {
+        info.default.as_ref().map(|expr| match expr {
+            Some(expr) => {
+                let mut expr = expr.clone();
+                ShadowedPathRewriter {
+                    shadowed_names: &shadowed_names,
+                    generic_param_names: &generic_param_names,
+                    in_module: !is_flat,
+                }
+                .visit_expr_mut(&mut expr);
+                quote! { #expr }
+            }
+            None => quote! { ::core::default::Default::default() },
+        })
+    };
+    let struct_generics = if module_generics.params.is_empty() {
         quote! {}
     } else {
-        let params = generics.params.iter();
+        let params = module_generics.params.iter();
         quote! { <#(#params),*> }
     };
     let where_clause = generics.where_clause.as_ref();
+    let module_where_clause = module_generics.where_clause.as_ref();
     let type_params: Vec<(String, syn::Ident)> = generics
         .type_params()
         .map(|param| (param.ident.to_string(), param.ident.clone()))
@@ -632,17 +2778,93 @@ fn derive_enum_event_impl(input: TokenStream, is_entity_event: bool) -> TokenStr
         .map(|(name, _)| name.clone())
         .collect();
 
+    // Shared fields lead every generated struct and are passed to the dispatch methods
+    let shared_attr_infos: Vec<FieldAttrInfo> = shared_fields
+        .iter()
+        .map(|field| analyze_field_attrs(&field.attrs, &mut errors))
+        .collect();
+    let shared_idents: Vec<&syn::Ident> = shared_fields
+        .iter()
+        .filter_map(|field| field.ident.as_ref())
+        .collect();
+    let shared_params: Vec<_> = shared_fields
+        .iter()
+        .map(|field| {
+            let ident = &field.ident;
+            let ty = &field.ty;
+            quote! { #ident: #ty }
+        })
+        .collect();
+    let mut shared_target: Option<&syn::Ident> = None;
+    let mut has_marked_shared_target = false;
+    for (field, info) in shared_fields.iter().zip(&shared_attr_infos) {
+        if info.has_deref {
+            errors.push(syn::Error::new(
+                field.span(),
+                "shared fields cannot be dereferenced",
+            ));
+        }
+        let is_target = info.is_event_target || is_entity_field(field);
+        if emits_event && is_target {
+            if shared_target.is_some() {
+                errors.push(syn::Error::new(
+                    field.span(),
+                    "multiple shared fields are event targets; only one field can be the target",
+                ));
+            } else {
+                shared_target = field.ident.as_ref();
+                has_marked_shared_target = info.is_event_target;
+            }
+        }
+    }
+    // Global variants keep the shared target as a plain field
+    let shared_field_tokens = |is_entity_variant: bool| -> Vec<_> {
+        shared_fields
+            .iter()
+            .zip(&shared_attr_infos)
+            .map(|(field, info)| {
+                let ident = &field.ident;
+                let ty = scoped_type(&field.ty);
+                let passthrough_attrs = &info.passthrough_attrs;
+                let target_attr = (is_entity_variant
+                    && shared_target.is_some()
+                    && shared_target == field.ident.as_ref())
+                .then(|| quote!(#[event_target]));
+                quote! {
+                    #(#passthrough_attrs)*
+                    #target_attr
+                    #item_vis #ident: #ty
+                }
+            })
+            .collect()
+    };
+
     // Generate struct definitions for each variant
     let mut struct_defs = Vec::new();
     let mut additional_impls = Vec::new();
-    let mut uses_deref_derives = false;
+    let mut conversions = Vec::new();
+    let mut skipped_patterns = Vec::new();
+    let mut has_entity_variants = false;
 
-    for variant in variants {
+    for (variant, (variant_attr_info, struct_ident)) in variants.iter().zip(&variant_infos) {
         let variant_ident = &variant.ident;
-        let struct_generics_tokens = struct_generics.clone();
 
-        // Parse variant-level propagate attributes
-        let variant_attr_info = analyze_variant_attrs(&variant.attrs);
+        // Field keys are validated even on skipped variants, so typos never go unnoticed
+        let field_attr_infos: Vec<FieldAttrInfo> = variant
+            .fields
+            .iter()
+            .map(|field| analyze_field_attrs(&field.attrs, &mut errors))
+            .collect();
+
+        // Skipped variants get no struct and are handed back by the generated dispatch
+        if variant_attr_info.skip {
+            let cfg_attrs = &variant_attr_info.cfg_attrs;
+            skipped_patterns.push(quote! {
+                #(#cfg_attrs)*
+                skipped @ #enum_name::#variant_ident { .. }
+            });
+            continue;
+        }
 
         // Determine propagate settings for this variant:
         // - If variant has propagate settings, use those (override enum-level)
@@ -661,7 +2883,7 @@ fn derive_enum_event_impl(input: TokenStream, is_entity_event: bool) -> TokenStr
 
         let mut usage_collector =
             GenericsUsageCollector::new(&type_param_names, &lifetime_param_names);
-        for field in &variant.fields {
+        for field in shared_fields.iter().chain(&variant.fields) {
             usage_collector.visit_type(&field.ty);
         }
         let unused_type_params: Vec<_> = type_params
@@ -686,375 +2908,845 @@ fn derive_enum_event_impl(input: TokenStream, is_entity_event: bool) -> TokenStr
         } else {
             Some(quote! { ::core::marker::PhantomData<(#(#phantom_entries ,)*)> })
         };
-        let mut extra_impl = None;
-
-        // For EntityEvent, check if the variant has an entity field
-        let has_entity_field = if is_entity_event {
-            match &variant.fields {
-                Fields::Named(fields) => {
-                    // Check for entity field or marked target field
-                    let target_fields: Vec<_> = fields
-                        .named
-                        .iter()
-                        .filter(|field| {
-                            let info = analyze_field_attrs(&field.attrs);
-                            info.is_event_target
-                                || field
-                                    .ident
-                                    .as_ref()
-                                    .is_some_and(|id| id == "entity")
-                        })
-                        .collect();
-
-                    assert!(target_fields.len() <= 1,
-                            "EnumEntityEvent: variant `{variant_ident}` has multiple fields marked as event target; only one field can be the target"
-                        );
-
-                    !target_fields.is_empty()
+        if !shared_fields.is_empty() {
+            if let Fields::Unnamed(fields) = &variant.fields {
+                errors.push(syn::Error::new(
+                    fields.span(),
+                    format!("tuple variant `{variant_ident}` cannot receive the shared `fields(...)`; use named fields"),
+                ));
+            }
+            for field in &variant.fields {
+                let clashes = field
+                    .ident
+                    .as_ref()
+                    .is_some_and(|ident| shared_idents.contains(&ident));
+                if clashes {
+                    errors.push(syn::Error::new(
+                        field.span(),
+                        "field has the same name as a shared field",
+                    ));
                 }
-                Fields::Unnamed(_) | Fields::Unit => false,
             }
+        }
+
+        // `EnumEntityEvent` variants target an entity unless marked `global`; other derives
+        // only promote variants marked `entity` or with a field marked `#[enum_event(target)]`
+        let has_marked_target = field_attr_infos.iter().any(|info| info.is_event_target);
+        let opts_in = has_marked_target || has_marked_shared_target;
+        let is_entity_variant = variant_attr_info.global_span.is_none()
+            && (is_entity_event
+                || variant_attr_info.entity_span.is_some()
+                || (emits_event && opts_in));
+        has_entity_variants |= is_entity_variant;
+        if !emits_event && (variant_attr_info.entity_span.is_some() || has_marked_target) {
+            errors.push(syn::Error::new(
+                variant_ident.span(),
+                format!("variant `{variant_ident}` targets an entity, but messages have no target; add `event` to the enum"),
+            ));
+        }
+        if let Some(span) = variant_attr_info.global_span {
+            if has_marked_target {
+                errors.push(syn::Error::new(
+                    span,
+                    format!("global variant `{variant_ident}` has no event target; remove #[enum_event(target)]"),
+                ));
+            } else if !emits_event {
+                errors.push(syn::Error::new(
+                    span,
+                    "`global` has no effect: messages have no target",
+                ));
+            } else if !is_entity_event && !opts_in {
+                errors.push(syn::Error::new(
+                    span,
+                    format!("`global` has no effect: variant `{variant_ident}` has no marked target field and is already a global event"),
+                ));
+            }
+            if has_any && is_entity_event {
+                errors.push(syn::Error::new(
+                    span,
+                    "`any` triggers an entity event for every variant, so no variant can be `global`",
+                ));
+            }
+        }
+        if !is_entity_variant && variant_has_propagate {
+            errors.push(syn::Error::new(
+                variant_ident.span(),
+                format!("`propagate` is only supported on entity variants, but `{variant_ident}` is a global event"),
+            ));
+        }
+
+        // Entity events need exactly one target field, unless a shared field is the target
+        let target_index = if !is_entity_variant {
+            None
+        } else if let Some(shared_target) = shared_target {
+            for (field, _) in variant
+                .fields
+                .iter()
+                .zip(&field_attr_infos)
+                .filter(|(_, info)| info.is_event_target)
+            {
+                errors.push(syn::Error::new(
+                    field.span(),
+                    format!("the shared field `{shared_target}` is already the event target"),
+                ));
+            }
+            None
         } else {
-            false
+            find_target_field(variant, &field_attr_infos, &mut errors)
+        };
+
+        let event_derive = derive_traits(is_entity_variant, emits_event, emits_message);
+        let forwarded_attrs = &variant_attr_info.forwarded_attrs;
+        let struct_attrs = if variant_attr_info.has_docs {
+            quote! { #(#forwarded_attrs)* }
+        } else {
+            quote! {
+                /// Event type corresponding to the enum variant.
+                #(#forwarded_attrs)*
+            }
+        };
+        let cfg_attrs = &variant_attr_info.cfg_attrs;
+        let variant_no_derives: HashSet<String> = no_derives
+            .union(&variant_attr_info.no_derives)
+            .cloned()
+            .collect();
+        // The variant's `deref` setting wins over the enum's, which wins over the `deref` feature
+        let derives_deref = variant_attr_info
+            .deref
+            .or(deref_override)
+            .unwrap_or(cfg!(feature = "deref"));
+        let deref_marker = field_attr_infos.iter().find(|info| info.has_deref);
+        let has_deref_marker = deref_marker.is_some();
+        // A marked field chooses for itself: `deref` is read-only, `deref_mut` is mutable too
+        let deref_traits = deref_derive(deref_marker.map_or_else(
+            || {
+                variant_attr_info
+                    .deref_mut
+                    .or(deref_mut_override)
+                    .unwrap_or(true)
+            },
+            |info| info.has_deref_mut,
+        ));
+        // Without a marked field, deref the only field, or with `deref_payload` the only field
+        // besides the target
+        let auto_deref_index = match target_index {
+            Some(target) if deref_payload_span.is_some() => {
+                let mut payload = (0..variant.fields.len()).filter(|index| *index != target);
+                payload.next().filter(|_| payload.next().is_none())
+            }
+            _ => (variant.fields.len() == 1).then_some(0),
         };
+        if variant_attr_info.deref == Some(true) && auto_deref_index.is_none() && !has_deref_marker
+        {
+            errors.push(syn::Error::new(
+                variant_ident.span(),
+                format!("variant `{variant_ident}` has no field to dereference; mark one with #[enum_event(deref)]"),
+            ));
+        }
+        if let Some(span) = variant_attr_info.default_span {
+            if matches!(variant.fields, Fields::Unit) && shared_fields.is_empty() {
+                errors.push(syn::Error::new(
+                    span,
+                    format!("unit variant `{variant_ident}` already derives `Default`"),
+                ));
+            } else if is_entity_variant {
+                let target_info = match target_index {
+                    Some(index) => field_attr_infos.get(index),
+                    None => shared_idents
+                        .iter()
+                        .position(|ident| Some(*ident) == shared_target)
+                        .map(|index| &shared_attr_infos[index]),
+                };
+                if target_info.is_some_and(|info| info.default.is_none()) {
+                    errors.push(syn::Error::new(
+                        span,
+                        format!("entity variant `{variant_ident}` needs a default target to implement `Default`; add #[enum_event(default = ...)] to its target field"),
+                    ));
+                }
+            }
+        }
+        let struct_derives = collect_struct_derives(
+            matches!(variant.fields, Fields::Unit) && shared_fields.is_empty(),
+            &variant_no_derives,
+            extra_derives.iter().chain(&variant_attr_info.derives),
+        );
+        if has_fan_in && variant_no_derives.contains("Clone") {
+            errors.push(syn::Error::new(
+                variant_ident.span(),
+                format!("`fan_in` clones every event, but variant `{variant_ident}` opts out of `Clone`"),
+            ));
+        }
 
-        assert!(!is_entity_event || has_entity_field,
-                "EnumEntityEvent: variant `{variant_ident}` must have an `entity: Entity` field or a field marked with #[enum_event(target)]"
-            );
+        // The parameters of `new`: shared fields first, then the variant's, skipping defaults
+        let (constructor_params, constructor_args): (Vec<_>, Vec<_>) = shared_fields
+            .iter()
+            .zip(&shared_attr_infos)
+            .filter(|(_, info)| info.default.is_none())
+            .filter_map(|(field, _)| Some((field.ident.clone()?, &field.ty)))
+            .chain(
+                variant
+                    .fields
+                    .iter()
+                    .zip(&field_attr_infos)
+                    .enumerate()
+                    .filter(|(_, (_, info))| info.default.is_none())
+                    .map(|(index, (field, _))| {
+                        let ident = field.ident.clone().unwrap_or_else(|| {
+                            syn::Ident::new(&format!("__arg{index}"), variant_ident.span())
+                        });
+                        (ident, &field.ty)
+                    }),
+            )
+            .map(|(ident, ty)| (quote! { #ident: #ty }, ident))
+            .unzip();
 
-        let event_derive = if is_entity_event {
-            quote! { EntityEvent }
-        } else {
-            quote! { Event }
+        let kind = match variant.fields {
+            Fields::Unnamed(_) => StructKind::Tuple,
+            Fields::Unit if shared_fields.is_empty() => StructKind::Unit,
+            // Unit variants receiving shared fields become named structs
+            Fields::Named(_) | Fields::Unit => StructKind::Named,
         };
 
-        let struct_def = match &variant.fields {
-            Fields::Unit => {
-                // Unit variants cannot be EntityEvents
-                assert!(!is_entity_event,
-                        "EnumEntityEvent: variant `{variant_ident}` is a unit variant; entity events must have at least an entity field"
-                    );
+        // Only one field can be dereferenced, and with several fields it must be marked
+        let deref_attr_fields = field_attr_infos
+            .iter()
+            .filter(|info| info.has_deref)
+            .count();
+        if deref_attr_fields > 1 {
+            push_multiple_deref_errors(&mut errors, variant, &field_attr_infos);
+        }
+        // An explicit `deref = false` cannot be undone by a field marker
+        let disabled_by = match (variant_attr_info.deref, deref_override) {
+            (Some(false), _) => Some("variant"),
+            (None, Some(false)) => Some("enum"),
+            _ => None,
+        };
+        if let Some(level) = disabled_by {
+            for (field, _) in variant
+                .fields
+                .iter()
+                .zip(&field_attr_infos)
+                .filter(|(_, info)| info.has_deref)
+            {
+                errors.push(syn::Error::new(
+                    field.span(),
+                    format!("`deref` has no effect: deref is disabled for this {level}"),
+                ));
+            }
+        }
+        let should_derive_deref = derives_deref
+            && (deref_attr_fields == 1 || (deref_attr_fields == 0 && auto_deref_index.is_some()));
+        let auto_mark_index =
+            auto_deref_index.filter(|_| should_derive_deref && deref_attr_fields == 0);
 
-                if let Some(phantom_type) = phantom_type.clone() {
-                    let (impl_generics_impl, ty_generics_impl, where_clause_impl) =
-                        generics.split_for_impl();
-                    extra_impl = Some(quote! {
-                        impl #impl_generics_impl #variant_ident #ty_generics_impl #where_clause_impl {
-                            #[inline]
-                            pub const fn new() -> Self {
-                                Self {
-                                    _phantom: ::core::marker::PhantomData,
-                                }
-                            }
-                        }
-                    });
-                    quote! {
-                        /// Event type corresponding to the enum variant.
-                        #[allow(unused_lifetimes, unused_type_parameters)]
-                        #[derive(Event, Clone, Copy, Debug, Default)]
-                        pub struct #variant_ident #struct_generics_tokens #where_clause {
-                            #[doc(hidden)]
-                            pub(crate) _phantom: #phantom_type,
-                        }
-                    }
-                } else {
+        let mut field_tokens = if kind == StructKind::Named {
+            shared_field_tokens(is_entity_variant)
+        } else {
+            Vec::new()
+        };
+        field_tokens.extend(
+            variant
+                .fields
+                .iter()
+                .zip(&field_attr_infos)
+                .enumerate()
+                .map(|(index, (field, info))| {
+                    let passthrough_attrs = &info.passthrough_attrs;
+                    let target_attr =
+                        (target_index == Some(index)).then(|| quote!(#[event_target]));
+                    // Bevy's `Deref` and `DerefMut` derives share the `#[deref]` marker
+                    let deref_attr = (should_derive_deref
+                        && (info.has_deref || auto_mark_index == Some(index)))
+                    .then(|| quote!(#[deref]));
+                    let name = field.ident.as_ref().map(|ident| quote! { #ident: });
+                    let ty = scoped_type(&field.ty);
                     quote! {
-                        /// Event type corresponding to the enum variant.
-                        #[allow(unused_lifetimes, unused_type_parameters)]
-                        #[derive(Event, Clone, Copy, Debug, Default)]
-                        pub struct #variant_ident #struct_generics_tokens #where_clause;
+                        #(#passthrough_attrs)*
+                        #target_attr
+                        #deref_attr
+                        #item_vis #name #ty
                     }
-                }
+                }),
+        );
+        if let Some(phantom_type) = &phantom_type {
+            let name = (kind != StructKind::Tuple).then(|| quote! { _phantom: });
+            field_tokens.push(quote! {
+                #[doc(hidden)]
+                pub(crate) #name #phantom_type
+            });
+        }
+        let struct_body = match kind {
+            StructKind::Tuple => quote! { (#(#field_tokens),*) #module_where_clause; },
+            StructKind::Unit if field_tokens.is_empty() => quote! { #module_where_clause; },
+            StructKind::Unit | StructKind::Named => {
+                quote! { #module_where_clause { #(#field_tokens),* } }
             }
-            Fields::Unnamed(fields) => {
-                // Tuple variants cannot be EntityEvents
-                assert!(!is_entity_event,
-                        "EnumEntityEvent: variant `{variant_ident}` is a tuple variant; entity events must use named fields with an `entity: Entity` field"
-                    );
+        };
+        let propagate_attr = is_entity_variant.then(|| {
+            entity_event_propagate_attr(
+                variant_propagate_value.as_ref(),
+                variant_auto_propagate,
+                !is_flat,
+            )
+        });
+        let deref_derive = should_derive_deref.then(|| quote! { #deref_traits, });
+        let struct_def = quote! {
+            #struct_attrs
+            #[allow(unused_lifetimes, unused_type_parameters)]
+            #[derive(#event_derive, #deref_derive #(#struct_derives),*)]
+            #propagate_attr
+            #item_vis struct #struct_ident #struct_generics #struct_body
+        };
 
-                let struct_generics_tokens = struct_generics_tokens.clone();
-                let field_infos: Vec<_> = fields
-                    .unnamed
-                    .iter()
-                    .map(|field| {
-                        let info = analyze_field_attrs(&field.attrs);
-                        (info, &field.ty)
-                    })
-                    .collect();
-                let field_count = field_infos.len();
-                let deref_attr_fields = field_infos
-                    .iter()
-                    .filter(|(info, _)| info.has_deref)
-                    .count();
+        // Every struct gets `new`, and named structs a builder; shared fields come first
+        let shape = StructShape {
+            ident: struct_ident,
+            generics: &module_generics,
+            cfg_attrs,
+            kind,
+            fields: shared_fields
+                .iter()
+                .zip(&shared_attr_infos)
+                .chain(variant.fields.iter().zip(&field_attr_infos))
+                .enumerate()
+                .map(|(index, (field, info))| StructField {
+                    ident: field.ident.clone().unwrap_or_else(|| {
+                        syn::Ident::new(&format!("__arg{index}"), variant_ident.span())
+                    }),
+                    ty: scoped_type(&field.ty),
+                    default: scoped_default(info),
+                })
+                .collect(),
+            has_shared_fields: !shared_fields.is_empty(),
+            has_phantom: phantom_type.is_some(),
+        };
+        additional_impls.push(shape.new_impl());
+        if has_builder(&variant.fields, &shared_fields) {
+            additional_impls.push(builder_items(&shape, &item_vis, forwarded_attrs));
+        }
+        if variant_attr_info.default_span.is_some() {
+            additional_impls.push(shape.default_impl());
+        }
 
-                assert!(!(field_count > 1 && deref_attr_fields > 1),
-                        "EnumEvent: variant `{variant_ident}` has multiple fields marked for deref (e.g., #[enum_event(deref)]); only one field can be dereferenced"
-                    );
+        // Destructure the variant and move its data into the generated struct (and back)
+        let (_, ty_generics_expr, _) = generics.split_for_impl();
+        let turbofish = ty_generics_expr.as_turbofish();
+        let struct_path = quote! { #module_prefix #struct_ident #turbofish };
+        let phantom_init = phantom_type
+            .as_ref()
+            .map(|_| quote! { ::core::marker::PhantomData });
+        let phantom_rest = phantom_type.as_ref().map(|_| quote! { .. });
+        let mut target_binding = None;
+        let (variant_pattern, struct_expr, struct_pattern) = match &variant.fields {
+            Fields::Unit if shared_fields.is_empty() => {
+                let struct_expr = match &phantom_init {
+                    Some(init) => quote! { #struct_path { _phantom: #init } },
+                    None => quote! { #struct_path },
+                };
+                let struct_pattern = match &phantom_rest {
+                    Some(rest) => quote! { #module_prefix #struct_ident { #rest } },
+                    None => quote! { #module_prefix #struct_ident },
+                };
+                (
+                    quote! { #enum_name::#variant_ident },
+                    struct_expr,
+                    struct_pattern,
+                )
+            }
+            Fields::Unnamed(fields) => {
+                let bindings: Vec<_> = (0..fields.unnamed.len())
+                    .map(|index| syn::Ident::new(&format!("__field{index}"), variant_ident.span()))
+                    .collect();
+                target_binding = target_index.map(|index| {
+                    let binding = &bindings[index];
+                    quote! { #binding }
+                });
+                let phantom_init = phantom_init.iter();
+                let phantom_rest = phantom_rest.iter();
+                (
+                    quote! { #enum_name::#variant_ident(#(#bindings),*) },
+                    quote! { #struct_path(#(#bindings,)* #(#phantom_init)*) },
+                    quote! { #module_prefix #struct_ident(#(#bindings,)* #(#phantom_rest)*) },
+                )
+            }
+            Fields::Named(_) | Fields::Unit => {
+                let bindings: Vec<_> = variant.fields.iter().map(|field| &field.ident).collect();
+                target_binding = match shared_target.filter(|_| is_entity_variant) {
+                    Some(ident) => Some(quote! { #ident }),
+                    None => target_index.map(|index| {
+                        let ident = bindings[index];
+                        quote! { #ident }
+                    }),
+                };
+                let phantom_init = phantom_init.iter();
+                // Shared fields have no counterpart in the enum and are dropped when converting back
+                let rest = if shared_fields.is_empty() {
+                    phantom_rest.clone()
+                } else {
+                    Some(quote! { .. })
+                };
+                let rest = rest.iter();
+                (
+                    quote! { #enum_name::#variant_ident { #(#bindings),* } },
+                    quote! { #struct_path { #(#shared_idents,)* #(#bindings,)* #(_phantom: #phantom_init)* } },
+                    quote! { #module_prefix #struct_ident { #(#bindings,)* #(#rest)* } },
+                )
+            }
+        };
+        conversions.push(VariantConversion {
+            struct_ident: struct_ident.clone(),
+            struct_type: quote! { #module_prefix #struct_ident #ty_generics_expr },
+            // The variant pattern doubles as the expression rebuilding the variant
+            variant_expr: variant_pattern.clone(),
+            variant_pattern,
+            struct_expr,
+            struct_pattern,
+            target_binding,
+            propagates: is_entity_variant && variant_propagate_value.is_some(),
+            cfg_attrs: variant_attr_info.cfg_attrs.clone(),
+            shortcut_ident: syn::Ident::new(
+                &format!("{}_event", to_snake_case(&variant_ident.to_string())),
+                variant_ident.span(),
+            ),
+            constructor_params,
+            constructor_args,
+        });
 
-                let should_derive_deref =
-                    cfg!(feature = "deref") && (field_count == 1 || deref_attr_fields == 1);
+        struct_defs.push(struct_def);
+    }
 
-                let mut field_tokens: Vec<_> = field_infos
-                    .iter()
-                    .map(|(info, ty)| {
-                        let passthrough_attrs = info.passthrough_attrs.iter();
-                        let mut marker_attrs = Vec::new();
-
-                        if should_derive_deref {
-                            if info.has_deref {
-                                marker_attrs.push(quote!(#[deref]));
-                            }
-                            if info.has_deref_mut {
-                                marker_attrs.push(quote!(#[deref_mut]));
-                            }
-                        }
+    // Shortcuts are named after the variant alone, so differently cased variants can collide
+    let mut seen_shortcuts = HashSet::new();
+    for conversion in &conversions {
+        let shortcut_ident = &conversion.shortcut_ident;
+        if !seen_shortcuts.insert(shortcut_ident.to_string()) {
+            errors.push(syn::Error::new(
+                shortcut_ident.span(),
+                format!("more than one variant generates a shortcut named `{shortcut_ident}`; rename the variant"),
+            ));
+        }
+    }
 
-                        quote! {
-                            #(#passthrough_attrs)*
-                            #(#marker_attrs)*
-                            pub #ty
-                        }
-                    })
-                    .collect();
+    // Enums with skipped variants hand them back, so callers can tell nothing was dispatched
+    let has_skipped = !skipped_patterns.is_empty();
 
-                if let Some(phantom_type) = phantom_type.clone() {
-                    field_tokens.push(quote! {
-                        #[doc(hidden)]
-                        pub(crate) #phantom_type
-                    });
+    // Umbrella event carrying the whole enum value, triggered alongside each variant event
+    let any_ident = aux_ident("Any");
+    if has_any {
+        push_name_conflict_errors(
+            &mut errors,
+            &conversions,
+            &any_ident,
+            "the umbrella event generated by `any`",
+        );
 
-                    let (impl_generics_impl, ty_generics_impl, where_clause_impl) =
-                        generics.split_for_impl();
-                    let arg_idents: Vec<_> = (0..field_infos.len())
-                        .map(|index| {
-                            syn::Ident::new(&format!("__arg{index}"), variant_ident.span())
-                        })
-                        .collect();
-                    let arg_defs: Vec<_> = field_infos
-                        .iter()
-                        .enumerate()
-                        .map(|(idx, (_, ty))| {
-                            let ident = &arg_idents[idx];
-                            quote! { #ident: #ty }
-                        })
-                        .collect();
-                    let arg_values = arg_idents.iter();
-
-                    extra_impl = Some(quote! {
-                        impl #impl_generics_impl #variant_ident #ty_generics_impl #where_clause_impl {
-                            #[inline]
-                            pub fn new(#(#arg_defs),*) -> Self {
-                                Self(#(#arg_values),*, ::core::marker::PhantomData)
-                            }
-                        }
-                    });
+        let (_, ty_generics_any, _) = generics.split_for_impl();
+        let derive_deref = deref_override.unwrap_or(cfg!(feature = "deref"));
+        let deref_derives = if derive_deref {
+            let deref_traits = deref_derive(deref_mut_override.unwrap_or(true));
+            quote! { #deref_traits, }
+        } else {
+            quote! {}
+        };
+        let any_derive = derive_traits(is_entity_event, emits_event, emits_message);
+        let any_def = if is_entity_event {
+            let propagate_attr =
+                entity_event_propagate_attr(propagate_value.as_ref(), has_auto_propagate, !is_flat);
+            let deref_marker = derive_deref.then(|| quote! { #[deref] });
+            quote! {
+                /// Umbrella event carrying the whole enum value, triggered alongside every variant event.
+                #[derive(#any_derive, #deref_derives Clone)]
+                #propagate_attr
+                #item_vis struct #any_ident #struct_generics #module_where_clause {
+                    /// The entity targeted by the variant event.
+                    #[event_target]
+                    #item_vis entity: ::bevy::prelude::Entity,
+                    /// The enum value the variant event was triggered from.
+                    #deref_marker
+                    #item_vis value: #enum_path #ty_generics_any,
                 }
+            }
+        } else {
+            quote! {
+                /// Umbrella type carrying the whole enum value, dispatched alongside every variant.
+                #[derive(#any_derive, #deref_derives Clone)]
+                #item_vis struct #any_ident #struct_generics (
+                    /// The enum value the variant was dispatched from.
+                    #item_vis #enum_path #ty_generics_any,
+                ) #module_where_clause;
+            }
+        };
+        struct_defs.push(any_def);
+    }
 
-                if should_derive_deref {
-                    uses_deref_derives = true;
-                    quote! {
-                        /// Event type corresponding to the enum variant.
-                        #[allow(unused_lifetimes, unused_type_parameters)]
-                        #[derive(Event, Deref, DerefMut, Clone, Debug)]
-                        pub struct #variant_ident #struct_generics_tokens(#(#field_tokens),*) #where_clause;
-                    }
-                } else {
-                    quote! {
-                        /// Event type corresponding to the enum variant.
-                        #[allow(unused_lifetimes, unused_type_parameters)]
-                        #[derive(Event, Clone, Debug)]
-                        pub struct #variant_ident #struct_generics_tokens(#(#field_tokens),*) #where_clause;
+    // Plugin re-triggering buffered enum messages as their per-variant events
+    let fan_out_plugin = if has_fan_out {
+        let plugin_ident = aux_ident("FanOutPlugin");
+        let system_ident = aux_fn_ident("fan_out");
+        let span = fan_out_span.unwrap_or_else(proc_macro2::Span::call_site);
+        if !generics.params.is_empty() {
+            errors.push(syn::Error::new(
+                span,
+                "`fan_out` is not supported on generic enums",
+            ));
+        }
+        if !emits_event {
+            errors.push(syn::Error::new(
+                span,
+                "`fan_out` requires the generated types to be events; add `event`",
+            ));
+        }
+        if !shared_fields.is_empty() {
+            errors.push(syn::Error::new(
+                span,
+                "`fan_out` cannot supply the shared `fields(...)` of the generated structs",
+            ));
+        }
+        push_name_conflict_errors(
+            &mut errors,
+            &conversions,
+            &plugin_ident,
+            "the plugin generated by `fan_out`",
+        );
+        let plugin_doc = format!(
+            "Plugin reading buffered [`{enum_name}`]({enum_doc_path}) messages and triggering each value as its per-variant event.\n\nRuns [`{system_ident}`] in [`Update`](::bevy::prelude::Update) unless another schedule is given with [`{plugin_ident}::new`]."
+        );
+        let system_doc =
+            format!("Triggers the per-variant event for every buffered `{enum_name}` message.");
+        // Skipped variants have no event, so their messages are dropped
+        let discard_skipped = has_skipped.then(|| quote! { let _ = });
+        quote! {
+            #[doc = #plugin_doc]
+            #item_vis struct #plugin_ident {
+                schedule: ::bevy::ecs::schedule::InternedScheduleLabel,
+            }
+
+            impl #plugin_ident {
+                /// Creates the plugin running its fan-out system in `schedule`.
+                pub fn new(schedule: impl ::bevy::ecs::schedule::ScheduleLabel) -> Self {
+                    Self {
+                        schedule: schedule.intern(),
                     }
                 }
             }
-            Fields::Named(fields) => {
-                let struct_generics_tokens = struct_generics_tokens.clone();
-                let field_infos: Vec<_> = fields
-                    .named
-                    .iter()
-                    .map(|field| {
-                        let info = analyze_field_attrs(&field.attrs);
-                        let field_name = field
-                            .ident
-                            .as_ref()
-                            .expect("Named fields must have identifiers")
-                            .clone();
-                        (info, field_name, &field.ty)
-                    })
-                    .collect();
-                let field_count = field_infos.len();
-                let deref_attr_fields = field_infos
-                    .iter()
-                    .filter(|(info, _, _)| info.has_deref)
-                    .count();
-
-                assert!(!(field_count > 1 && deref_attr_fields > 1),
-                        "EnumEvent: variant `{variant_ident}` has multiple fields marked for deref (e.g., #[enum_event(deref)]); only one field can be dereferenced"
-                    );
 
-                let should_derive_deref =
-                    cfg!(feature = "deref") && (field_count == 1 || deref_attr_fields == 1);
-
-                let auto_mark_single_field =
-                    should_derive_deref && deref_attr_fields == 0 && field_count == 1;
+            impl ::core::default::Default for #plugin_ident {
+                fn default() -> Self {
+                    Self::new(::bevy::prelude::Update)
+                }
+            }
 
-                let mut field_tokens: Vec<_> = field_infos
-                    .iter()
-                    .map(|(info, field_name, field_type)| {
-                        let passthrough_attrs = info.passthrough_attrs.iter();
-                        let mut marker_attrs = Vec::new();
+            impl ::bevy::prelude::Plugin for #plugin_ident {
+                fn build(&self, app: &mut ::bevy::prelude::App) {
+                    app.add_message::<#enum_path>();
+                    app.add_systems(self.schedule, #system_ident);
+                }
+            }
 
-                        // Add event_target attribute for EntityEvent
-                        if is_entity_event && (info.is_event_target || field_name == "entity") {
-                            marker_attrs.push(quote!(#[event_target]));
-                        }
+            #[doc = #system_doc]
+            #item_vis fn #system_ident(
+                mut messages: ::bevy::prelude::MessageReader<#enum_path>,
+                mut commands: ::bevy::prelude::Commands,
+            ) {
+                for message in messages.read() {
+                    #discard_skipped ::core::clone::Clone::clone(message).trigger(&mut commands);
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
 
-                        if should_derive_deref {
-                            if info.has_deref {
-                                marker_attrs.push(quote!(#[deref]));
-                            }
-                            if info.has_deref_mut {
-                                marker_attrs.push(quote!(#[deref_mut]));
-                            } else if auto_mark_single_field {
-                                marker_attrs.push(quote!(#[deref]));
-                            }
-                        } else if auto_mark_single_field {
-                            marker_attrs.push(quote!(#[deref]));
-                        }
+    // Plugin writing every triggered variant event back as a buffered enum message
+    let fan_in_plugin = if has_fan_in {
+        let plugin_ident = aux_ident("FanInPlugin");
+        let span = fan_in_span.unwrap_or_else(proc_macro2::Span::call_site);
+        if !generics.params.is_empty() {
+            errors.push(syn::Error::new(
+                span,
+                "`fan_in` is not supported on generic enums",
+            ));
+        }
+        if !emits_event {
+            errors.push(syn::Error::new(
+                span,
+                "`fan_in` requires the generated types to be events; add `event`",
+            ));
+        }
+        if has_fan_out {
+            errors.push(syn::Error::new(
+                span,
+                "`fan_in` and `fan_out` cannot be combined, as every event would be re-triggered forever",
+            ));
+        }
+        push_name_conflict_errors(
+            &mut errors,
+            &conversions,
+            &plugin_ident,
+            "the plugin generated by `fan_in`",
+        );
+        let observers = conversions.iter().map(|conversion| {
+            let struct_ident = &conversion.struct_ident;
+            let cfg_attrs = &conversion.cfg_attrs;
+            // Propagating events reach global observers once per entity; only the first hop counts
+            let first_hop_guard = conversion.propagates.then(|| {
+                quote! {
+                    if ::bevy::prelude::EntityEvent::event_target(event.event()) != event.original_event_target() {
+                        return;
+                    }
+                }
+            });
+            quote! {
+                #(#cfg_attrs)*
+                app.add_observer(
+                    |event: ::bevy::prelude::On<#struct_ident>,
+                     mut messages: ::bevy::prelude::MessageWriter<#enum_path>| {
+                        #first_hop_guard
+                        messages.write(#enum_path::from(::core::clone::Clone::clone(event.event())));
+                    },
+                );
+            }
+        });
+        let plugin_doc = format!(
+            "Plugin writing every triggered variant event back as a buffered [`{enum_name}`]({enum_doc_path}) message."
+        );
+        quote! {
+            #[doc = #plugin_doc]
+            ///
+            /// One observer per generated struct converts the event into the enum, so messages are
+            /// written in the order the events were triggered.
+            #item_vis struct #plugin_ident;
 
-                        quote! {
-                            #(#passthrough_attrs)*
-                            #(#marker_attrs)*
-                            pub #field_name: #field_type,
-                        }
-                    })
-                    .collect();
+            #[allow(deprecated)]
+            impl ::bevy::prelude::Plugin for #plugin_ident {
+                fn build(&self, app: &mut ::bevy::prelude::App) {
+                    app.add_message::<#enum_path>();
+                    #(#observers)*
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
 
-                if let Some(phantom_type) = phantom_type.clone() {
-                    field_tokens.push(quote! {
-                        #[doc(hidden)]
-                        pub(crate) _phantom: #phantom_type,
-                    });
+    // Bevy only implements `Event`/`Message` for generated structs that are `Send + Sync + 'static`,
+    // so generic enums need matching bounds before their values can be triggered or written.
+    let static_bounds: Vec<_> = type_params
+        .iter()
+        .map(|(_, ident)| quote! { #ident: ::core::marker::Send + ::core::marker::Sync + 'static })
+        .chain(
+            lifetime_params
+                .iter()
+                .map(|(_, lt)| quote! { #lt: 'static }),
+        )
+        .collect();
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    let ty_generics_turbofish = ty_generics.as_turbofish();
+    let dispatch_methods = Dispatch {
+        conversions: &conversions,
+        skipped_patterns: &skipped_patterns,
+        any_path: has_any.then(|| quote! { #module_prefix #any_ident #ty_generics_turbofish }),
+        is_entity_event,
+        emits_event,
+        emits_message,
+        shared_fields: &shared_fields,
+        static_bounds: &static_bounds,
+    }
+    .methods();
 
-                    let (impl_generics_impl, ty_generics_impl, where_clause_impl) =
-                        generics.split_for_impl();
-                    let arg_defs: Vec<_> = field_infos
-                        .iter()
-                        .map(|(_, field_name, field_type)| {
-                            quote! { #field_name: #field_type }
-                        })
-                        .collect();
-                    let field_names: Vec<_> = field_infos
-                        .iter()
-                        .map(|(_, field_name, _)| field_name)
-                        .collect();
-
-                    extra_impl = Some(quote! {
-                        impl #impl_generics_impl #variant_ident #ty_generics_impl #where_clause_impl {
-                            #[inline]
-                            pub fn new(#(#arg_defs),*) -> Self {
-                                Self {
-                                    #(#field_names),*,
-                                    _phantom: ::core::marker::PhantomData,
-                                }
-                            }
-                        }
-                    });
+    // Registration function adding every generated message type to an app
+    let message_registration = if emits_message {
+        let add_messages_ident = aux_fn_ident("add_messages");
+        let registrations = conversions
+            .iter()
+            .map(|conversion| {
+                let struct_ident = &conversion.struct_ident;
+                let cfg_attrs = &conversion.cfg_attrs;
+                quote! {
+                    #(#cfg_attrs)*
+                    app.add_message::<#struct_ident #ty_generics>();
                 }
+            })
+            .chain(has_any.then(|| quote! { app.add_message::<#any_ident #ty_generics>(); }));
+        let (module_impl_generics, _, _) = module_generics.split_for_impl();
+        let registration_where_clause = match (module_where_clause, static_bounds.is_empty()) {
+            (None, true) => quote! {},
+            (None, false) => quote! { where #(#static_bounds),* },
+            (Some(clause), true) => quote! { #clause },
+            (Some(clause), false) => {
+                let predicates = clause.predicates.iter();
+                quote! { where #(#predicates,)* #(#static_bounds),* }
+            }
+        };
+        quote! {
+            /// Registers every generated message type with the app via `add_message`.
+            #[allow(deprecated)]
+            #item_vis fn #add_messages_ident #module_impl_generics (app: &mut ::bevy::prelude::App) #registration_where_clause {
+                #(#registrations)*
+            }
+        }
+    } else {
+        quote! {}
+    };
+    let conversion_impls = conversions.iter().map(|conversion| {
+        let VariantConversion {
+            struct_ident,
+            struct_type,
+            variant_pattern,
+            struct_expr,
+            struct_pattern,
+            variant_expr,
+            cfg_attrs,
+            shortcut_ident,
+            constructor_params,
+            constructor_args,
+            ..
+        } = conversion;
+        let shortcut_doc = format!("Creates the `{struct_ident}` event, taking the same arguments as its `new`.");
+        // Without the shared fields an enum value cannot become a struct, so they are taken as arguments
+        let try_from_impl = if shared_fields.is_empty() {
+            quote! {
+                #(#cfg_attrs)*
+                #[allow(deprecated)]
+                impl #impl_generics ::core::convert::TryFrom<#enum_name #ty_generics> for #struct_type #where_clause {
+                    type Error = #enum_name #ty_generics;
 
-                // Note: We accept #[enum_event(propagate)] on the enum, but generate #[entity_event(propagate)]
-                // on the struct because that's what Bevy's EntityEvent derive expects
-                // Generate variant-specific propagate attributes
-                let propagate_attr = if is_entity_event && variant_propagate_value.is_some() {
-                    match variant_propagate_value.clone() {
-                        Some(tokens) if tokens.is_empty() => {
-                            if variant_auto_propagate {
-                                quote! { #[entity_event(auto_propagate, propagate)] }
-                            } else {
-                                quote! { #[entity_event(propagate)] }
-                            }
-                        }
-                        Some(tokens) => {
-                            let adjusted_tokens = if let Ok(mut ty) = syn::parse2::<syn::Type>(tokens.clone()) {
-                                adjust_propagate_type_for_module(&mut ty);
-                                quote! { #ty }
-                            } else {
-                                quote! { #tokens }
-                            };
-
-                            if variant_auto_propagate {
-                                quote! { #[entity_event(auto_propagate, propagate = #adjusted_tokens)] }
-                            } else {
-                                quote! { #[entity_event(propagate = #adjusted_tokens)] }
-                            }
-                        }
-                        None => quote! {},
-                    }
-                } else {
-                    quote! {}
-                };
-
-                if should_derive_deref {
-                    uses_deref_derives = true;
-                    quote! {
-                        /// Event type corresponding to the enum variant.
-                        #[allow(unused_lifetimes, unused_type_parameters)]
-                        #[derive(#event_derive, Deref, DerefMut, Clone, Debug)]
-                        #propagate_attr
-                        pub struct #variant_ident #struct_generics_tokens #where_clause {
-                            #(#field_tokens)*
+                    #[inline]
+                    #[allow(unreachable_patterns)]
+                    fn try_from(value: #enum_name #ty_generics) -> ::core::result::Result<Self, Self::Error> {
+                        match value {
+                            #variant_pattern => ::core::result::Result::Ok(#struct_expr),
+                            other => ::core::result::Result::Err(other),
                         }
                     }
-                } else {
-                    quote! {
-                        /// Event type corresponding to the enum variant.
-                        #[allow(unused_lifetimes, unused_type_parameters)]
-                        #[derive(#event_derive, Clone, Debug)]
-                        #propagate_attr
-                        pub struct #variant_ident #struct_generics_tokens #where_clause {
-                            #(#field_tokens)*
+                }
+            }
+        } else {
+            quote! {
+                #(#cfg_attrs)*
+                #[allow(deprecated)]
+                impl #impl_generics #struct_type #where_clause {
+                    /// Builds the event from a matching enum value and the shared fields, or
+                    /// returns the value unchanged if it is another variant.
+                    #[inline]
+                    #[allow(unreachable_patterns)]
+                    pub fn try_from_enum(
+                        value: #enum_name #ty_generics
+                        #(, #shared_params)*
+                    ) -> ::core::result::Result<Self, #enum_name #ty_generics> {
+                        match value {
+                            #variant_pattern => ::core::result::Result::Ok(#struct_expr),
+                            other => ::core::result::Result::Err(other),
                         }
                     }
                 }
             }
         };
+        quote! {
+            #(#cfg_attrs)*
+            #[allow(deprecated)]
+            impl #impl_generics ::core::convert::From<#struct_type> for #enum_name #ty_generics #where_clause {
+                #[inline]
+                fn from(event: #struct_type) -> Self {
+                    let #struct_pattern = event;
+                    #variant_expr
+                }
+            }
 
-        struct_defs.push(struct_def);
-        if let Some(extra) = extra_impl {
-            additional_impls.push(extra);
+            #try_from_impl
+
+            #(#cfg_attrs)*
+            #[allow(deprecated)]
+            impl #impl_generics #enum_name #ty_generics #where_clause {
+                #[doc = #shortcut_doc]
+                #[inline]
+                pub fn #shortcut_ident(#(#constructor_params),*) -> #struct_type {
+                    <#struct_type>::new(#(#constructor_args),*)
+                }
+            }
         }
-    }
+    });
 
-    let deref_imports = if cfg!(feature = "deref") && uses_deref_derives {
-        quote! {
-            use bevy::prelude::{Deref, DerefMut};
+    // Enum-level propagation applies to the entity variants only
+    if let Some(span) = propagate_span {
+        if !has_entity_variants {
+            errors.push(syn::Error::new(
+                span,
+                "`propagate` has no effect: no variant is an entity event",
+            ));
         }
-    } else {
-        quote! {}
-    };
+    }
+    if let Some(span) = deref_payload_span {
+        if !has_entity_variants {
+            errors.push(syn::Error::new(
+                span,
+                "`deref_payload` has no effect: no variant is an entity event",
+            ));
+        }
+    }
 
-    let event_import = if is_entity_event {
+    let generated_items = quote! {
+        #(#struct_defs)*
+        #(#additional_impls)*
+        #message_registration
+        #fan_out_plugin
+        #fan_in_plugin
+    };
+    // Entity events name `Entity` even when the enum's scope is unreachable (e.g. inside a function)
+    let entity_import = has_entity_variants.then(|| {
         quote! {
-            use bevy::prelude::{Entity, EntityEvent};
+            #[allow(unused_imports)]
+            use ::bevy::prelude::Entity;
         }
+    });
+    // The enum's docs and lint levels carry over to its module; `expect` would go unfulfilled
+    let module_attrs: Vec<_> = input
+        .attrs
+        .iter()
+        .filter(|attr| {
+            ["doc", "allow", "warn", "deny", "forbid"]
+                .iter()
+                .any(|name| attr.path().is_ident(name))
+        })
+        .collect();
+    let module_doc = if module_attrs.iter().any(|attr| attr.path().is_ident("doc")) {
+        quote! {}
     } else {
         quote! {
-            use bevy::prelude::Event;
+            /// Generated module containing event types for each enum variant.
         }
     };
+    let generated_items = match &module_name {
+        Some(module_name) => quote! {
+            #module_doc
+            #(#module_attrs)*
+            #[allow(deprecated)]
+            #vis mod #module_name {
+                // Field types and bounds are written in the enum's scope
+                #[allow(unused_imports)]
+                use super::*;
+                #entity_import
+
+                #generated_items
+            }
+        },
+        None => generated_items,
+    };
+
+    errors.finish()?;
 
     let expanded = quote! {
-        /// Generated module containing event types for each enum variant.
-        pub mod #module_name {
-            #event_import
-            #deref_imports
+        #generated_items
 
-            #(#struct_defs)*
-            #(#additional_impls)*
+        #[allow(deprecated)]
+        impl #impl_generics #enum_name #ty_generics #where_clause {
+            #dispatch_methods
         }
+
+        #(#conversion_impls)*
     };
 
-    TokenStream::from(expanded)
+    Ok(expanded)
 }
 
 #[cfg(test)]
@@ -1069,5 +3761,341 @@ mod tests {
         assert_eq!(to_snake_case("FSM"), "fsm");
         assert_eq!(to_snake_case("MyHTTPSConnection"), "my_https_connection");
     }
-}
 
+    fn error_messages(input: DeriveInput, kind: DeriveKind) -> Vec<String> {
+        match expand_enum_event(&input, kind) {
+            Ok(_) => Vec::new(),
+            Err(errors) => errors.into_iter().map(|error| error.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_non_enum_input_is_an_error() {
+        let input: DeriveInput = syn::parse_quote! {
+            struct Player;
+        };
+        assert_eq!(
+            error_messages(input, DeriveKind::Event),
+            vec!["enum_event derives can only be used on enums"]
+        );
+    }
+
+    #[test]
+    fn test_entity_event_errors_are_reported_together() {
+        let input: DeriveInput = syn::parse_quote! {
+            enum PlayerEvent {
+                Spawned,
+                Moved(u32),
+                Swapped(Entity, Entity),
+                Damaged { amount: f32 },
+                Traded { entity: Entity, #[enum_event(target)] other: Entity },
+            }
+        };
+        let messages = error_messages(input, DeriveKind::EntityEvent);
+        assert_eq!(messages.len(), 5);
+        assert!(messages[0].contains("`Spawned` is a unit variant"));
+        assert!(messages[1].contains("`Moved` needs an `Entity` field"));
+        assert!(messages[2].contains("`Swapped` has several `Entity` fields"));
+        assert!(messages[3].contains("`Damaged` must have an `entity: Entity` field"));
+        assert!(messages[4].contains("`Traded` has multiple event target fields"));
+    }
+
+    #[test]
+    fn test_multiple_deref_fields_are_an_error() {
+        let input: DeriveInput = syn::parse_quote! {
+            enum GameEvent {
+                Scored {
+                    #[enum_event(deref)]
+                    points: u32,
+                    #[enum_event(deref)]
+                    bonus: u32,
+                },
+            }
+        };
+        let messages = error_messages(input, DeriveKind::Event);
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].contains("multiple fields marked for deref"));
+    }
+
+    #[test]
+    fn test_deref_switch_errors() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[enum_event(deref)]
+            enum GameEvent {
+                #[enum_event(deref = true)]
+                Scored { player: u32, points: u32 },
+                #[enum_event(deref = "no")]
+                Reset,
+            }
+        };
+        let messages = error_messages(input, DeriveKind::Event);
+        assert_eq!(messages.len(), 3);
+        assert_eq!(messages[0], "expected `deref = true` or `deref = false`");
+        assert!(messages[1].contains("expected boolean literal"));
+        assert!(messages[2].contains("`Scored` has no field to dereference"));
+    }
+
+    #[test]
+    fn test_malformed_attributes_are_errors() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[enum_event(propagate = 42, any)]
+            enum UiEvent {
+                #[enum_event(no_derive(Hash))]
+                Clicked { entity: Entity },
+            }
+        };
+        let messages = error_messages(input, DeriveKind::EntityEvent);
+        assert_eq!(messages.len(), 2);
+        assert!(messages[0].contains("expected a relationship type"));
+        assert!(messages[1].contains("no_derive accepts only"));
+    }
+
+    #[test]
+    fn test_invalid_affixes_are_errors() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[enum_event(prefix = "1")]
+            #[enum_event(suffix = "-Event")]
+            enum PlayerState {
+                Idle,
+            }
+        };
+        let messages = error_messages(input, DeriveKind::Event);
+        assert_eq!(messages.len(), 2);
+        assert!(messages[0].starts_with("`1` would not form valid struct names"));
+        assert!(messages[1].starts_with("`-Event` would not form valid struct names"));
+    }
+
+    #[test]
+    fn test_misspelled_keys_suggest_the_closest_key() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[enum_event(fan_ot)]
+            enum UiEvent {
+                #[enum_event(propogate)]
+                Clicked {
+                    #[enum_event(traget)]
+                    entity: Entity,
+                },
+            }
+        };
+        let messages = error_messages(input, DeriveKind::EntityEvent);
+        assert_eq!(
+            messages,
+            vec![
+                "unknown enum enum_event key `fan_ot`; did you mean `fan_out`?",
+                "unknown variant enum_event key `propogate`; did you mean `propagate`?",
+                "unknown field enum_event key `traget`; did you mean `target`?",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_propagate_needs_entity_variants() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[enum_event(propagate)]
+            enum GameEvent {
+                #[enum_event(bogus)]
+                Started,
+            }
+        };
+        let messages = error_messages(input, DeriveKind::Event);
+        assert_eq!(messages.len(), 2);
+        assert!(messages[0]
+            .starts_with("unknown variant enum_event key `bogus`; expected one of `skip`"));
+        assert_eq!(
+            messages[1],
+            "`propagate` has no effect: no variant is an entity event"
+        );
+
+        // Enum-level propagation reaches the entity variants of a mixed enum
+        let input: DeriveInput = syn::parse_quote! {
+            #[enum_event(auto_propagate, propagate)]
+            enum GameEvent {
+                Started,
+                #[enum_event(entity)]
+                Damaged { entity: Entity },
+            }
+        };
+        assert!(error_messages(input, DeriveKind::Event).is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_keys_and_meaningless_combinations() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[enum_event(any)]
+            #[enum_event(auto_propagate, any)]
+            enum PlayerEvent {
+                #[enum_event(auto_propagate)]
+                Damaged { entity: Entity },
+                #[enum_event(skip, rename = "Hidden")]
+                Reset,
+            }
+        };
+        let messages = error_messages(input, DeriveKind::EntityEvent);
+        assert_eq!(messages.len(), 4);
+        assert_eq!(messages[0], "duplicate enum_event key `any`");
+        assert!(
+            messages[1].contains("`auto_propagate` has no effect without `propagate` on the enum")
+        );
+        assert!(messages[2].contains("without `propagate` on the same variant"));
+        assert!(messages[3].contains("skipped variants generate no struct"));
+    }
+
+    #[test]
+    fn test_options_without_effect_are_errors() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[enum_event(deref_payload, event)]
+            enum GameEvent {
+                #[enum_event(global)]
+                Started,
+                #[enum_event(skip)]
+                Internal {
+                    #[enum_event(traget)]
+                    id: u32,
+                },
+            }
+        };
+        let messages = error_messages(input, DeriveKind::Event);
+        assert_eq!(
+            messages,
+            vec![
+                "`event` has no effect: `EnumEvent` already derives `Event`",
+                "`global` has no effect: variant `Started` has no marked target field and is already a global event",
+                "unknown field enum_event key `traget`; did you mean `target`?",
+                "`deref_payload` has no effect: no variant is an entity event",
+            ]
+        );
+
+        let input: DeriveInput = syn::parse_quote! {
+            #[enum_event(message)]
+            enum InputAction {
+                #[enum_event(global)]
+                Jump,
+            }
+        };
+        let messages = error_messages(input, DeriveKind::Message);
+        assert_eq!(
+            messages,
+            vec![
+                "`message` has no effect: `EnumMessage` already derives `Message`",
+                "`global` has no effect: messages have no target",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_global_variant_errors() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[enum_event(any)]
+            enum UiEvent {
+                Clicked { entity: Entity },
+                #[enum_event(global, propagate)]
+                Closed,
+                #[enum_event(global, entity)]
+                Moved { entity: Entity },
+            }
+        };
+        let messages = error_messages(input, DeriveKind::EntityEvent);
+        assert_eq!(messages.len(), 4);
+        assert_eq!(
+            messages[0],
+            "a variant cannot be both `global` and `entity`"
+        );
+        assert!(messages[1].contains("no variant can be `global`"));
+        assert!(messages[2].contains("`Closed` is a global event"));
+        assert!(messages[3].contains("no variant can be `global`"));
+
+        let input: DeriveInput = syn::parse_quote! {
+            enum InputAction {
+                #[enum_event(entity)]
+                Jump(Entity),
+            }
+        };
+        let messages = error_messages(input, DeriveKind::Message);
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].contains("messages have no target"));
+    }
+
+    #[test]
+    fn test_variant_default_errors() {
+        let input: DeriveInput = syn::parse_quote! {
+            enum PlayerEvent {
+                #[enum_event(default)]
+                Damaged { entity: Entity, #[enum_event(default = 1.0)] amount: f32 },
+                #[enum_event(global, default)]
+                Paused,
+            }
+        };
+        let messages = error_messages(input, DeriveKind::EntityEvent);
+        assert_eq!(messages.len(), 2);
+        assert!(messages[0].contains("`Damaged` needs a default target"));
+        assert_eq!(
+            messages[1],
+            "unit variant `Paused` already derives `Default`"
+        );
+    }
+
+    #[test]
+    fn test_field_named_build_skips_the_builder() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[enum_event(fields(build: u32))]
+            enum ReleaseEvent {
+                Deployed { version: u32 },
+                Rolled,
+            }
+        };
+        assert!(error_messages(input, DeriveKind::Event).is_empty());
+    }
+
+    #[test]
+    fn test_deref_marker_on_disabled_deref() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[enum_event(deref = false)]
+            enum ScoreEvent {
+                Scored { #[enum_event(deref)] player: u32, points: u32 },
+                #[enum_event(deref = false)]
+                Tied { #[enum_event(deref_mut)] first: u32, second: u32 },
+                #[enum_event(deref = true)]
+                Missed { #[enum_event(deref)] player: u32, reason: u32 },
+            }
+        };
+        assert_eq!(
+            error_messages(input, DeriveKind::Event),
+            vec![
+                "`deref` has no effect: deref is disabled for this enum",
+                "`deref` has no effect: deref is disabled for this variant",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_shortcut_conflicts() {
+        let input: DeriveInput = syn::parse_quote! {
+            enum NetworkEvent {
+                HTTPError(u16),
+                HttpError { code: u16 },
+            }
+        };
+        assert_eq!(
+            error_messages(input, DeriveKind::Event),
+            vec!["more than one variant generates a shortcut named `http_error_event`; rename the variant"]
+        );
+    }
+
+    #[test]
+    fn test_shared_field_conflicts() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[enum_event(fields(entity: Entity), fan_out)]
+            enum DoorEvent {
+                Opened(u32),
+                Closed { entity: Entity },
+                Locked { #[enum_event(target)] key: Entity },
+            }
+        };
+        let messages = error_messages(input, DeriveKind::EntityEvent);
+        assert_eq!(messages.len(), 4);
+        assert!(messages[0].contains("tuple variant `Opened` cannot receive the shared"));
+        assert!(messages[1].contains("same name as a shared field"));
+        assert!(messages[2].contains("`entity` is already the event target"));
+        assert!(messages[3].contains("`fan_out` cannot supply the shared"));
+    }
+}
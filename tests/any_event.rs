@@ -0,0 +1,120 @@
+//! Tests for the opt-in `#[enum_event(any)]` umbrella event.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_enum_event::{EnumEntityEvent, EnumEvent};
+
+use common::{log_app, logged, Log};
+
+#[derive(EnumEvent, Clone, Debug, PartialEq)]
+#[enum_event(any)]
+#[allow(dead_code)]
+enum PlayerState {
+    Idle,
+    Running(u32),
+    Jumping { height: u32 },
+}
+
+#[test]
+fn test_any_fires_alongside_variant_event() {
+    let mut app = log_app();
+    app.add_observer(|event: On<player_state::Running>, mut log: ResMut<Log>| {
+        log.push(format!("running {}", event.0));
+    });
+    app.add_observer(|event: On<player_state::Any>, mut log: ResMut<Log>| {
+        log.push(format!("any {:?}", event.0));
+    });
+
+    PlayerState::Running(3).trigger_world(app.world_mut());
+    PlayerState::Idle.trigger_world(app.world_mut());
+
+    app.add_systems(Update, |mut commands: Commands| {
+        PlayerState::Jumping { height: 2 }.trigger(&mut commands);
+    });
+    app.update();
+
+    assert_eq!(
+        logged(&app),
+        vec![
+            "running 3",
+            "any Running(3)",
+            "any Idle",
+            "any Jumping { height: 2 }",
+        ]
+    );
+}
+
+#[test]
+fn test_any_not_fired_by_direct_struct_trigger() {
+    let mut app = log_app();
+    app.add_observer(|_: On<player_state::Any>, mut log: ResMut<Log>| {
+        log.push("any");
+    });
+
+    app.world_mut().trigger(player_state::Idle);
+
+    assert!(logged(&app).is_empty());
+}
+
+#[derive(Component, Default)]
+struct Seen(Vec<String>);
+
+#[derive(EnumEntityEvent, Clone, Copy, Debug)]
+#[enum_event(any, auto_propagate, propagate)]
+#[allow(dead_code)]
+enum UiEvent {
+    Click { entity: Entity },
+    Scroll { entity: Entity, delta: i32 },
+}
+
+#[test]
+fn test_entity_any_targets_entity_and_propagates() {
+    let mut app = App::new();
+
+    let parent = app.world_mut().spawn(Seen::default()).id();
+    let child = app
+        .world_mut()
+        .spawn((Seen::default(), ChildOf(parent)))
+        .id();
+
+    for entity in [parent, child] {
+        app.world_mut().entity_mut(entity).observe(
+            move |event: On<ui_event::Any>, mut query: Query<&mut Seen>| {
+                assert_eq!(event.entity, entity);
+                query
+                    .get_mut(entity)
+                    .unwrap()
+                    .0
+                    .push(format!("{:?}", event.value));
+            },
+        );
+    }
+
+    UiEvent::Scroll {
+        entity: child,
+        delta: -1,
+    }
+    .trigger_world(app.world_mut());
+
+    let expected = format!(
+        "{:?}",
+        UiEvent::Scroll {
+            entity: child,
+            delta: -1
+        }
+    );
+    assert_eq!(
+        app.world().get::<Seen>(child).unwrap().0,
+        vec![expected.clone()]
+    );
+    assert_eq!(app.world().get::<Seen>(parent).unwrap().0, vec![expected]);
+}
+
+#[cfg(feature = "deref")]
+#[test]
+fn test_any_derefs_to_enum() {
+    let any = player_state::Any(PlayerState::Running(1));
+    let state: &PlayerState = &any;
+    assert_eq!(*state, PlayerState::Running(1));
+}
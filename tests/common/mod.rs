@@ -0,0 +1,26 @@
+//! Fixtures shared by the integration tests.
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+
+/// Lines pushed by test observers, in the order they ran.
+#[derive(Resource, Default)]
+pub struct Log(pub Vec<String>);
+
+impl Log {
+    pub fn push(&mut self, line: impl Into<String>) {
+        self.0.push(line.into());
+    }
+}
+
+/// Creates an app with an empty [`Log`].
+pub fn log_app() -> App {
+    let mut app = App::new();
+    app.init_resource::<Log>();
+    app
+}
+
+/// Returns the lines logged so far.
+pub fn logged(app: &App) -> &[String] {
+    &app.world().resource::<Log>().0
+}
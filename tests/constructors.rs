@@ -0,0 +1,120 @@
+//! Tests for the generated `new` constructors, builders and enum-side shortcuts.
+
+use bevy::prelude::*;
+use bevy_enum_event::{EnumEntityEvent, EnumEvent};
+
+#[derive(EnumEntityEvent, Clone, Copy, Debug, PartialEq)]
+#[allow(dead_code)]
+enum PlayerEvent {
+    Damaged {
+        entity: Entity,
+        amount: f32,
+    },
+    Healed(Entity, f32),
+    Respawned {
+        entity: Entity,
+        #[enum_event(default = 100)]
+        health: u32,
+    },
+}
+
+#[test]
+fn test_new_is_always_generated() {
+    let entity = Entity::from_bits(1);
+    let damaged = player_event::Damaged::new(entity, 5.0);
+    assert_eq!((damaged.entity, damaged.amount), (entity, 5.0));
+    let healed = player_event::Healed::new(entity, 2.0);
+    assert_eq!((healed.0, healed.1), (entity, 2.0));
+}
+
+#[test]
+fn test_builder_sets_fields_by_name() {
+    let entity = Entity::from_bits(1);
+    let damaged = player_event::Damaged::builder()
+        .amount(5.0)
+        .entity(entity)
+        .build();
+    assert_eq!((damaged.entity, damaged.amount), (entity, 5.0));
+
+    // Fields with a default may be left unset
+    let respawned = player_event::Respawned::builder().entity(entity).build();
+    assert_eq!(respawned.health, 100);
+
+    // Setting a field again replaces its value
+    let respawned = player_event::Respawned::builder()
+        .health(50)
+        .entity(Entity::from_bits(2))
+        .entity(entity)
+        .build();
+    assert_eq!((respawned.entity, respawned.health), (entity, 50));
+}
+
+#[test]
+fn test_enum_shortcuts() {
+    let entity = Entity::from_bits(1);
+    let damaged = PlayerEvent::damaged_event(entity, 5.0);
+    assert_eq!(
+        PlayerEvent::from(damaged),
+        PlayerEvent::Damaged {
+            entity,
+            amount: 5.0
+        }
+    );
+    assert_eq!(PlayerEvent::healed_event(entity, 1.0).1, 1.0);
+    assert_eq!(PlayerEvent::respawned_event(entity).health, 100);
+}
+
+// Constructors keep their shape whether or not a generic is used by the variant
+#[derive(EnumEvent, Clone, Debug)]
+#[allow(dead_code)]
+enum ValueEvent<T: Clone + Send + Sync + 'static> {
+    Reset,
+    Changed { value: T },
+    Counted(u32),
+}
+
+#[test]
+fn test_generic_constructors() {
+    let changed = ValueEvent::<String>::changed_event("a".to_string());
+    assert_eq!(changed.value, "a");
+    let built = value_event::Changed::<String>::builder()
+        .value("b".to_string())
+        .build();
+    assert_eq!(built.value, "b");
+
+    let _ = value_event::Reset::<String>::new();
+    let _ = ValueEvent::<String>::reset_event();
+    assert_eq!(value_event::Counted::<String>::new(3).0, 3);
+}
+
+// A field named `build` would collide with the builder's terminal method, so that struct has no builder
+#[derive(EnumEvent, Clone, Debug)]
+#[allow(dead_code)]
+enum ReleaseEvent {
+    Deployed { build: u32, version: u32 },
+    RolledBack { version: u32 },
+}
+
+#[test]
+fn test_field_named_build_skips_the_builder() {
+    let deployed = release_event::Deployed::new(7, 2);
+    assert_eq!((deployed.build, deployed.version), (7, 2));
+    assert_eq!(ReleaseEvent::deployed_event(7, 2).build, 7);
+    let rolled_back = release_event::RolledBack::builder().version(1).build();
+    assert_eq!(rolled_back.version, 1);
+}
+
+// Builder state parameters follow the enum's defaulted const parameter, so they get defaults too
+#[derive(EnumEvent, Clone, Debug)]
+#[allow(dead_code)]
+enum BufferEvent<const N: usize = 3> {
+    Filled { values: [u8; N] },
+}
+
+#[test]
+fn test_const_default_builder() {
+    let filled = buffer_event::Filled::builder().values([1, 2, 3]).build();
+    assert_eq!(filled.values, [1, 2, 3]);
+    let filled = BufferEvent::<2>::filled_event([4, 5]);
+    assert_eq!(filled.values, [4, 5]);
+}
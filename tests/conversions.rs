@@ -0,0 +1,125 @@
+//! Tests for the generated `From`/`TryFrom` conversions between an enum
+//! and its generated variant structs.
+
+use bevy::prelude::Entity;
+use bevy_enum_event::{EnumEntityEvent, EnumEvent};
+
+#[derive(EnumEvent, Clone, Debug, PartialEq)]
+#[allow(dead_code)]
+enum GameEvent {
+    GameOver,
+    Victory(u32),
+    Pair(u32, i32),
+    ScoreChanged { team: u32, score: i32 },
+}
+
+#[test]
+fn test_struct_into_enum() {
+    assert_eq!(GameEvent::from(game_event::GameOver), GameEvent::GameOver);
+    assert_eq!(
+        GameEvent::from(game_event::Victory(3)),
+        GameEvent::Victory(3)
+    );
+    assert_eq!(
+        GameEvent::from(game_event::Pair(1, -1)),
+        GameEvent::Pair(1, -1)
+    );
+
+    let event: GameEvent = game_event::ScoreChanged { team: 2, score: 9 }.into();
+    assert_eq!(event, GameEvent::ScoreChanged { team: 2, score: 9 });
+}
+
+#[test]
+fn test_enum_try_into_struct() {
+    let score = game_event::ScoreChanged::try_from(GameEvent::ScoreChanged { team: 1, score: 4 })
+        .expect("variant should match");
+    assert_eq!(score.team, 1);
+    assert_eq!(score.score, 4);
+
+    let pair: game_event::Pair = GameEvent::Pair(7, 8).try_into().unwrap();
+    assert_eq!((pair.0, pair.1), (7, 8));
+
+    assert!(game_event::GameOver::try_from(GameEvent::GameOver).is_ok());
+}
+
+#[test]
+fn test_try_from_mismatch_returns_original_enum() {
+    let original = GameEvent::Victory(5);
+    let err = game_event::ScoreChanged::try_from(original.clone()).unwrap_err();
+    assert_eq!(err, original);
+
+    let err = game_event::GameOver::try_from(GameEvent::Pair(1, 2)).unwrap_err();
+    assert_eq!(err, GameEvent::Pair(1, 2));
+}
+
+#[derive(EnumEvent, Clone, Debug, PartialEq)]
+#[allow(dead_code)]
+enum GenericEvent<T>
+where
+    T: Clone + std::fmt::Debug,
+{
+    Owned(T),
+    Counted(u32),
+    Labeled { label: u32 },
+    Unit,
+}
+
+#[test]
+fn test_phantom_marker_conversions() {
+    let counted = generic_event::Counted::<String>::new(3);
+    assert_eq!(
+        GenericEvent::from(counted),
+        GenericEvent::<String>::Counted(3)
+    );
+
+    let labeled = generic_event::Labeled::<String>::new(4);
+    assert_eq!(
+        GenericEvent::from(labeled),
+        GenericEvent::<String>::Labeled { label: 4 }
+    );
+
+    assert_eq!(
+        GenericEvent::from(generic_event::Unit::<String>::new()),
+        GenericEvent::<String>::Unit
+    );
+
+    let counted = generic_event::Counted::try_from(GenericEvent::<String>::Counted(9)).unwrap();
+    assert_eq!(counted.0, 9);
+
+    let owned = generic_event::Owned::try_from(GenericEvent::Owned("hi".to_string())).unwrap();
+    assert_eq!(owned.0, "hi");
+
+    assert!(generic_event::Unit::try_from(GenericEvent::<String>::Unit).is_ok());
+    assert_eq!(
+        generic_event::Unit::try_from(GenericEvent::<String>::Counted(1)).unwrap_err(),
+        GenericEvent::Counted(1)
+    );
+}
+
+#[derive(EnumEntityEvent, Clone, Copy, Debug, PartialEq)]
+#[allow(dead_code)]
+enum PlayerEvent {
+    Spawned { entity: Entity },
+    Damaged { entity: Entity, amount: f32 },
+}
+
+#[test]
+fn test_entity_event_conversions() {
+    let entity = Entity::from_bits(11);
+
+    let damaged = player_event::Damaged {
+        entity,
+        amount: 2.5,
+    };
+    assert_eq!(
+        PlayerEvent::from(damaged),
+        PlayerEvent::Damaged {
+            entity,
+            amount: 2.5
+        }
+    );
+
+    let spawned = player_event::Spawned::try_from(PlayerEvent::Spawned { entity }).unwrap();
+    assert_eq!(spawned.entity, entity);
+    assert!(player_event::Damaged::try_from(PlayerEvent::Spawned { entity }).is_err());
+}
@@ -0,0 +1,157 @@
+//! Tests for enum- and variant-level `#[enum_event(deref = ...)]`, which override the `deref`
+//! feature, and for read-only deref. These hold with the feature enabled or disabled.
+
+use bevy_enum_event::{EnumEntityEvent, EnumEvent};
+use std::ops::{Deref, DerefMut};
+
+#[derive(EnumEvent, Clone, Debug)]
+#[enum_event(deref = true)]
+#[allow(dead_code)]
+enum NetworkEvent {
+    MessageReceived(String),
+    Scored {
+        #[enum_event(deref)]
+        player: u32,
+        points: u32,
+    },
+    // The variant setting wins over the enum's
+    #[enum_event(deref = false)]
+    Disconnected(u32),
+}
+
+// Would conflict with a generated `Deref` impl
+impl Deref for network_event::Disconnected {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        "disconnected"
+    }
+}
+
+#[test]
+fn test_enum_level_deref_enabled() {
+    let mut message = network_event::MessageReceived("hello".to_string());
+    message.push('!');
+    assert_eq!(message.deref(), "hello!");
+
+    let scored = network_event::Scored {
+        player: 7,
+        points: 3,
+    };
+    assert_eq!(*scored, 7);
+}
+
+#[test]
+fn test_variant_level_deref_disabled() {
+    let disconnected = network_event::Disconnected(1);
+    assert_eq!(&*disconnected, "disconnected");
+    assert_eq!(disconnected.0, 1);
+}
+
+#[derive(EnumEntityEvent, Clone, Copy, Debug)]
+#[enum_event(deref = false, any)]
+#[allow(dead_code)]
+enum PlayerEvent {
+    Healed {
+        entity: bevy::prelude::Entity,
+    },
+    #[enum_event(deref = true)]
+    Damaged {
+        entity: bevy::prelude::Entity,
+        #[enum_event(deref_mut)]
+        amount: f32,
+    },
+}
+
+impl Deref for player_event::Healed {
+    type Target = bevy::prelude::Entity;
+
+    fn deref(&self) -> &bevy::prelude::Entity {
+        &self.entity
+    }
+}
+
+// The umbrella event follows the enum setting
+impl Deref for player_event::Any {
+    type Target = PlayerEvent;
+
+    fn deref(&self) -> &PlayerEvent {
+        &self.value
+    }
+}
+
+#[test]
+fn test_enum_level_deref_disabled() {
+    let entity = bevy::prelude::Entity::from_bits(1);
+    let mut damaged = player_event::Damaged {
+        entity,
+        amount: 2.0,
+    };
+    *damaged.deref_mut() += 1.0;
+    assert_eq!(damaged.amount, 3.0);
+    assert_eq!(*player_event::Healed { entity }, entity);
+}
+
+#[derive(EnumEvent, Clone, Debug)]
+#[enum_event(deref = true, deref_mut = false)]
+#[allow(dead_code)]
+enum ChatEvent {
+    // Unmarked single fields follow the enum's `deref_mut`
+    Posted(String),
+    // A marked field chooses for itself
+    Edited {
+        #[enum_event(deref)]
+        text: String,
+        revision: u32,
+    },
+    Pinned {
+        #[enum_event(deref_mut)]
+        text: String,
+        pinned: bool,
+    },
+    // Bevy's bare marker keeps deriving both traits
+    Quoted {
+        #[deref]
+        text: String,
+        author: u32,
+    },
+}
+
+// Would conflict with generated `DerefMut` impls
+impl DerefMut for chat_event::Posted {
+    fn deref_mut(&mut self) -> &mut String {
+        &mut self.0
+    }
+}
+
+impl DerefMut for chat_event::Edited {
+    fn deref_mut(&mut self) -> &mut String {
+        &mut self.text
+    }
+}
+
+#[test]
+fn test_read_only_deref() {
+    let posted = chat_event::Posted("hi".to_string());
+    assert_eq!(posted.deref(), "hi");
+
+    let edited = chat_event::Edited {
+        text: "fixed".to_string(),
+        revision: 2,
+    };
+    assert_eq!(edited.deref(), "fixed");
+
+    let mut pinned = chat_event::Pinned {
+        text: "rules".to_string(),
+        pinned: true,
+    };
+    pinned.push('!');
+    assert_eq!(pinned.text, "rules!");
+
+    let mut quoted = chat_event::Quoted {
+        text: "so".to_string(),
+        author: 1,
+    };
+    quoted.push('?');
+    assert_eq!(quoted.text, "so?");
+}
@@ -0,0 +1,100 @@
+//! Tests for `#[enum_event(deref_payload)]`, which derefs entity events to their only field
+//! besides the target.
+#![cfg(feature = "deref")]
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_enum_event::EnumEntityEvent;
+use std::ops::Deref;
+
+use common::{log_app, logged, Log};
+
+#[derive(EnumEntityEvent, Clone, Copy, Debug)]
+#[enum_event(deref_payload)]
+#[allow(dead_code)]
+enum CombatEvent {
+    Damaged {
+        entity: Entity,
+        amount: f32,
+    },
+    Healed(f32, Entity),
+    Blocked {
+        #[enum_event(target)]
+        defender: Entity,
+        attacker: Entity,
+    },
+    // Marked fields still win
+    Attacked {
+        entity: Entity,
+        #[enum_event(deref)]
+        damage: f32,
+        critical: bool,
+    },
+}
+
+// No payload to deref, so this would otherwise conflict with a generated impl
+#[derive(EnumEntityEvent, Clone, Copy, Debug)]
+#[enum_event(deref_payload)]
+#[allow(dead_code)]
+enum LifeEvent {
+    Spawned { entity: Entity },
+}
+
+impl Deref for life_event::Spawned {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        "spawned"
+    }
+}
+
+#[test]
+fn test_deref_to_the_payload_field() {
+    let entity = Entity::from_bits(1);
+    let other = Entity::from_bits(2);
+
+    assert_eq!(
+        *combat_event::Damaged {
+            entity,
+            amount: 4.0
+        },
+        4.0
+    );
+    assert_eq!(*combat_event::Healed(2.0, entity), 2.0);
+    assert_eq!(
+        *combat_event::Blocked {
+            defender: entity,
+            attacker: other
+        },
+        other
+    );
+    assert_eq!(
+        *combat_event::Attacked {
+            entity,
+            damage: 3.0,
+            critical: true
+        },
+        3.0
+    );
+    assert_eq!(life_event::Spawned { entity }.deref(), "spawned");
+}
+
+#[test]
+fn test_observers_read_the_payload() {
+    let mut app = log_app();
+    let player = app.world_mut().spawn_empty().id();
+    app.world_mut().entity_mut(player).observe(
+        |event: On<combat_event::Damaged>, mut log: ResMut<Log>| {
+            log.push(format!("damaged {}", **event));
+        },
+    );
+
+    CombatEvent::Damaged {
+        entity: player,
+        amount: 5.0,
+    }
+    .trigger_world(app.world_mut());
+
+    assert_eq!(logged(&app), vec!["damaged 5"]);
+}
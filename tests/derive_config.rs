@@ -0,0 +1,101 @@
+//! Tests for `#[enum_event(derive(...))]` and `#[enum_event(no_derive(...))]`,
+//! which configure the derives of the generated structs.
+
+use bevy::prelude::*;
+use bevy_enum_event::EnumEvent;
+use std::collections::HashSet;
+
+#[derive(EnumEvent, Clone, Debug)]
+#[enum_event(derive(PartialEq, Eq, Hash))]
+#[allow(dead_code)]
+enum GameEvent {
+    Started,
+    Scored(u32),
+    #[enum_event(derive(PartialOrd, Ord))]
+    Ranked {
+        rank: u32,
+    },
+}
+
+fn assert_default<T: Default>() {}
+
+#[test]
+fn test_enum_level_derives_apply_to_every_struct() {
+    assert_eq!(game_event::Scored(2), game_event::Scored(2));
+    assert_ne!(game_event::Scored(2), game_event::Scored(3));
+
+    let set: HashSet<_> = [game_event::Started, game_event::Started].into();
+    assert_eq!(set.len(), 1);
+
+    // Standard derives are kept alongside the requested ones
+    let started = game_event::Started;
+    let copied = started;
+    assert_eq!(format!("{:?}", started), format!("{:?}", copied));
+    assert_default::<game_event::Started>();
+}
+
+#[test]
+fn test_variant_level_derives_add_to_enum_level() {
+    let low = game_event::Ranked { rank: 1 };
+    let high = game_event::Ranked { rank: 2 };
+    assert!(low < high);
+    assert_eq!(low.clone().max(high.clone()), high);
+}
+
+trait Effect: Send + Sync {
+    fn strength(&self) -> u32;
+}
+
+struct Burn;
+
+impl Effect for Burn {
+    fn strength(&self) -> u32 {
+        5
+    }
+}
+
+#[derive(Resource, Default)]
+struct Total(u32);
+
+// Payloads that are neither `Clone` nor `Debug`
+#[derive(EnumEvent)]
+#[enum_event(no_derive(Clone, Debug))]
+#[allow(dead_code)]
+enum EffectEvent {
+    Applied(Box<dyn Effect>),
+    Cleared,
+    #[enum_event(no_derive(Default))]
+    Nothing,
+}
+
+#[test]
+fn test_no_derive_allows_non_clone_payloads() {
+    let mut app = App::new();
+    app.init_resource::<Total>();
+    app.add_observer(
+        |event: On<effect_event::Applied>, mut total: ResMut<Total>| {
+            total.0 += event.0.strength();
+        },
+    );
+
+    EffectEvent::Applied(Box::new(Burn)).trigger_world(app.world_mut());
+    EffectEvent::Cleared.trigger_world(app.world_mut());
+
+    assert_eq!(app.world().resource::<Total>().0, 5);
+    assert_default::<effect_event::Cleared>();
+    let _nothing = effect_event::Nothing;
+}
+
+#[derive(EnumEvent, Clone, Debug)]
+#[enum_event(no_derive(Copy), derive(Clone, PartialEq))]
+#[allow(dead_code)]
+enum StateEvent {
+    Idle,
+}
+
+#[test]
+fn test_no_derive_copy_keeps_clone() {
+    let idle = state_event::Idle;
+    let cloned = idle.clone();
+    assert_eq!(idle, cloned);
+}
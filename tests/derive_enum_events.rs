@@ -160,16 +160,16 @@ fn test_multi_field_variants() {
     assert_eq!(m2.b, 42);
 }
 
-// Test deref support for multi-field variants when #[enum_event(deref)] is provided
+// Test deref support for multi-field variants when #[enum_event(deref_mut)] is provided
 #[cfg(feature = "deref")]
 #[test]
 fn test_multi_field_deref_with_attribute() {
     #[derive(EnumEvent, Clone)]
     #[allow(dead_code)]
     enum MultiFieldDeref {
-        Tuple(#[enum_event(deref)] String, i32),
+        Tuple(#[enum_event(deref_mut)] String, i32),
         Named {
-            #[enum_event(deref)]
+            #[enum_event(deref_mut)]
             value: String,
             other: i32,
         },
@@ -0,0 +1,145 @@
+//! Tests for `#[derive(EnumMessage)]` and its generated registration and write helpers.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_enum_event::EnumMessage;
+
+use common::{log_app, logged, Log};
+
+#[derive(EnumMessage, Clone, Debug)]
+#[allow(dead_code)]
+enum PlayerState {
+    Idle,
+    Running(u32),
+    Jumping { height: u32 },
+}
+
+fn read_player_states(
+    mut idle: MessageReader<player_state::Idle>,
+    mut running: MessageReader<player_state::Running>,
+    mut jumping: MessageReader<player_state::Jumping>,
+    mut log: ResMut<Log>,
+) {
+    for _ in idle.read() {
+        log.push("idle");
+    }
+    for run in running.read() {
+        log.push(format!("running {}", run.0));
+    }
+    for jump in jumping.read() {
+        log.push(format!("jumping {}", jump.height));
+    }
+}
+
+fn player_state_app() -> App {
+    let mut app = log_app();
+    player_state::add_messages(&mut app);
+    app.add_systems(Update, read_player_states);
+    app
+}
+
+#[test]
+fn test_add_messages_registers_every_variant() {
+    let app = player_state_app();
+
+    assert!(app
+        .world()
+        .contains_resource::<Messages<player_state::Idle>>());
+    assert!(app
+        .world()
+        .contains_resource::<Messages<player_state::Running>>());
+    assert!(app
+        .world()
+        .contains_resource::<Messages<player_state::Jumping>>());
+}
+
+#[test]
+fn test_write_world_buffers_messages() {
+    let mut app = player_state_app();
+
+    PlayerState::Running(4).write_world(app.world_mut());
+    PlayerState::Jumping { height: 2 }.write_world(app.world_mut());
+    PlayerState::Idle.write_world(app.world_mut());
+
+    // Nothing is read until the reading system runs
+    assert!(logged(&app).is_empty());
+
+    app.update();
+
+    assert_eq!(logged(&app), vec!["idle", "running 4", "jumping 2"]);
+}
+
+#[test]
+fn test_write_via_commands() {
+    let mut app = player_state_app();
+
+    app.add_systems(PreUpdate, |mut commands: Commands| {
+        PlayerState::Running(1).write(&mut commands);
+    });
+    app.update();
+
+    assert_eq!(logged(&app), vec!["running 1"]);
+}
+
+#[test]
+fn test_message_conversions() {
+    let running = player_state::Running::try_from(PlayerState::Running(8)).unwrap();
+    assert_eq!(running.0, 8);
+
+    let state: PlayerState = player_state::Jumping { height: 3 }.into();
+    assert!(matches!(state, PlayerState::Jumping { height: 3 }));
+}
+
+#[derive(EnumMessage, Clone, Debug, PartialEq)]
+#[enum_event(any)]
+#[allow(dead_code)]
+enum InputAction {
+    Jump,
+    Move { x: i32 },
+}
+
+#[test]
+fn test_any_message_is_written_alongside_variant() {
+    let mut app = App::new();
+    input_action::add_messages(&mut app);
+
+    InputAction::Move { x: 2 }.write_world(app.world_mut());
+    InputAction::Jump.write_world(app.world_mut());
+
+    let any = app.world().resource::<Messages<input_action::Any>>();
+    let values: Vec<_> = any
+        .iter_current_update_messages()
+        .map(|any| any.0.clone())
+        .collect();
+    assert_eq!(values, vec![InputAction::Move { x: 2 }, InputAction::Jump]);
+
+    let moves = app.world().resource::<Messages<input_action::Move>>();
+    assert_eq!(moves.len(), 1);
+}
+
+#[derive(EnumMessage, Clone, Debug)]
+#[allow(dead_code)]
+enum GenericMessage<T>
+where
+    T: Clone + std::fmt::Debug,
+{
+    Value(T),
+    Reset,
+}
+
+#[test]
+fn test_generic_add_messages() {
+    let mut app = App::new();
+    generic_message::add_messages::<u8>(&mut app);
+
+    GenericMessage::Value(5_u8).write_world(app.world_mut());
+
+    let values = app
+        .world()
+        .resource::<Messages<generic_message::Value<u8>>>();
+    assert_eq!(values.len(), 1);
+    assert!(app
+        .world()
+        .contains_resource::<Messages<generic_message::Reset<u8>>>());
+}
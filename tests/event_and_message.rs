@@ -0,0 +1,119 @@
+//! Tests for `#[enum_event(event, message)]`, which makes every generated
+//! struct both an observable event and a buffered message.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_enum_event::{EnumEntityEvent, EnumEvent, EnumMessage};
+
+use common::{log_app, logged, Log};
+
+#[derive(EnumEvent, Clone, Debug)]
+#[enum_event(event, message)]
+#[allow(dead_code)]
+enum GameEvent {
+    Started,
+    Scored { points: u32 },
+}
+
+fn read_scores(mut scores: MessageReader<game_event::Scored>, mut log: ResMut<Log>) {
+    for scored in scores.read() {
+        log.push(format!("read {}", scored.points));
+    }
+}
+
+fn game_event_app() -> App {
+    let mut app = log_app();
+    game_event::add_messages(&mut app);
+    app.add_observer(|event: On<game_event::Scored>, mut log: ResMut<Log>| {
+        log.push(format!("observed {}", event.points));
+    });
+    app.add_systems(Update, read_scores);
+    app
+}
+
+#[test]
+fn test_trigger_and_write_world_reaches_observers_and_readers() {
+    let mut app = game_event_app();
+
+    GameEvent::Scored { points: 3 }.trigger_and_write_world(app.world_mut());
+    assert_eq!(logged(&app), vec!["observed 3"]);
+
+    app.update();
+    assert_eq!(logged(&app), vec!["observed 3", "read 3"]);
+}
+
+#[test]
+fn test_trigger_and_write_via_commands() {
+    let mut app = game_event_app();
+
+    app.add_systems(PreUpdate, |mut commands: Commands| {
+        GameEvent::Scored { points: 5 }.trigger_and_write(&mut commands);
+    });
+    app.update();
+
+    assert_eq!(logged(&app), vec!["observed 5", "read 5"]);
+}
+
+#[test]
+fn test_trigger_and_write_stay_independent() {
+    let mut app = game_event_app();
+
+    GameEvent::Scored { points: 1 }.trigger_world(app.world_mut());
+    GameEvent::Scored { points: 2 }.write_world(app.world_mut());
+    app.update();
+
+    assert_eq!(logged(&app), vec!["observed 1", "read 2"]);
+}
+
+#[derive(EnumMessage, Clone, Copy, Debug)]
+#[enum_event(event)]
+#[allow(dead_code)]
+enum InputAction {
+    Jump,
+}
+
+#[test]
+fn test_enum_message_with_event() {
+    let mut app = log_app();
+    input_action::add_messages(&mut app);
+    app.add_observer(|_: On<input_action::Jump>, mut log: ResMut<Log>| {
+        log.push("jump");
+    });
+
+    InputAction::Jump.trigger_and_write_world(app.world_mut());
+
+    assert_eq!(logged(&app), vec!["jump"]);
+    let jumps = app.world().resource::<Messages<input_action::Jump>>();
+    assert_eq!(jumps.len(), 1);
+}
+
+#[derive(EnumEntityEvent, Clone, Copy, Debug)]
+#[enum_event(message, any)]
+#[allow(dead_code)]
+enum PlayerEvent {
+    Damaged { entity: Entity, amount: u32 },
+}
+
+#[test]
+fn test_entity_event_with_message() {
+    let mut app = App::new();
+    player_event::add_messages(&mut app);
+
+    let player = app.world_mut().spawn_empty().id();
+    PlayerEvent::Damaged {
+        entity: player,
+        amount: 4,
+    }
+    .write_world(app.world_mut());
+
+    let damaged = app.world().resource::<Messages<player_event::Damaged>>();
+    let targets: Vec<_> = damaged
+        .iter_current_update_messages()
+        .map(|damaged| (damaged.entity, damaged.amount))
+        .collect();
+    assert_eq!(targets, vec![(player, 4)]);
+
+    let any = app.world().resource::<Messages<player_event::Any>>();
+    assert_eq!(any.len(), 1);
+}
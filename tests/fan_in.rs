@@ -0,0 +1,93 @@
+//! Tests for `#[enum_event(fan_in)]`, which writes every triggered variant
+//! event back as a buffered enum message.
+
+use bevy::prelude::*;
+use bevy_enum_event::{EnumEntityEvent, EnumEvent};
+
+#[derive(Resource, Default)]
+struct Collected(Vec<GameEvent>);
+
+#[derive(EnumEvent, Message, Clone, Debug, PartialEq)]
+#[enum_event(fan_in)]
+#[allow(dead_code)]
+enum GameEvent {
+    Started,
+    Victory(u32),
+    ScoreChanged { team: u32, score: i32 },
+}
+
+fn collect_game_events(mut messages: MessageReader<GameEvent>, mut log: ResMut<Collected>) {
+    log.0.extend(messages.read().cloned());
+}
+
+#[test]
+fn test_fan_in_preserves_trigger_order() {
+    let mut app = App::new();
+    app.add_plugins(game_event::FanInPlugin);
+    app.init_resource::<Collected>();
+    app.add_systems(Update, collect_game_events);
+
+    app.world_mut().trigger(game_event::Victory(2));
+    GameEvent::ScoreChanged { team: 1, score: 4 }.trigger_world(app.world_mut());
+    app.world_mut().trigger(game_event::Started);
+
+    app.add_systems(PreUpdate, |mut commands: Commands| {
+        commands.trigger(game_event::Victory(9));
+        GameEvent::Started.trigger(&mut commands);
+    });
+    app.update();
+
+    assert_eq!(
+        app.world().resource::<Collected>().0,
+        vec![
+            GameEvent::Victory(2),
+            GameEvent::ScoreChanged { team: 1, score: 4 },
+            GameEvent::Started,
+            GameEvent::Victory(9),
+            GameEvent::Started,
+        ]
+    );
+}
+
+#[derive(EnumEntityEvent, Message, Clone, Copy, Debug, PartialEq)]
+#[enum_event(fan_in)]
+#[allow(dead_code)]
+enum UiEvent {
+    Hovered {
+        entity: Entity,
+    },
+    #[enum_event(auto_propagate, propagate)]
+    Clicked {
+        entity: Entity,
+        button: u8,
+    },
+}
+
+#[test]
+fn test_fan_in_entity_events_written_once() {
+    let mut app = App::new();
+    app.add_plugins(ui_event::FanInPlugin);
+
+    let parent = app.world_mut().spawn_empty().id();
+    let child = app.world_mut().spawn(ChildOf(parent)).id();
+
+    UiEvent::Clicked {
+        entity: child,
+        button: 1,
+    }
+    .trigger_world(app.world_mut());
+    UiEvent::Hovered { entity: parent }.trigger_world(app.world_mut());
+
+    let messages = app.world().resource::<Messages<UiEvent>>();
+    let values: Vec<_> = messages.iter_current_update_messages().copied().collect();
+    assert_eq!(
+        values,
+        vec![
+            UiEvent::Clicked {
+                entity: child,
+                button: 1,
+            },
+            UiEvent::Hovered { entity: parent },
+        ]
+    );
+}
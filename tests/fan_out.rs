@@ -0,0 +1,132 @@
+//! Tests for `#[enum_event(fan_out)]`, which re-triggers buffered enum
+//! messages as their per-variant events.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_enum_event::{EnumEntityEvent, EnumEvent};
+
+use common::{log_app, logged, Log};
+
+#[derive(EnumEvent, Message, Clone, Debug)]
+#[enum_event(fan_out)]
+#[allow(dead_code)]
+enum PlayerState {
+    Idle,
+    Paused,
+    Running(u32),
+}
+
+fn player_state_app(plugin: player_state::FanOutPlugin) -> App {
+    let mut app = log_app();
+    app.add_plugins(plugin);
+    app.add_observer(|_: On<player_state::Paused>, mut log: ResMut<Log>| {
+        log.push("paused");
+    });
+    app.add_observer(|event: On<player_state::Running>, mut log: ResMut<Log>| {
+        log.push(format!("running {}", event.0));
+    });
+    app
+}
+
+#[test]
+fn test_fan_out_triggers_each_message_in_order() {
+    let mut app = player_state_app(player_state::FanOutPlugin::default());
+
+    app.world_mut().write_message(PlayerState::Running(2));
+    app.world_mut().write_message(PlayerState::Idle);
+    app.world_mut().write_message(PlayerState::Paused);
+
+    // Messages are only fanned out once the schedule runs
+    assert!(logged(&app).is_empty());
+
+    app.update();
+    assert_eq!(logged(&app), vec!["running 2", "paused"]);
+
+    // Each message is triggered exactly once
+    app.update();
+    assert_eq!(logged(&app).len(), 2);
+}
+
+#[test]
+fn test_fan_out_in_configured_schedule() {
+    let mut app = player_state_app(player_state::FanOutPlugin::new(PostUpdate));
+
+    app.add_systems(Update, |mut writer: MessageWriter<PlayerState>| {
+        writer.write(PlayerState::Paused);
+    });
+
+    // Messages written in `Update` are fanned out later in the same frame
+    app.update();
+    assert_eq!(logged(&app), vec!["paused"]);
+
+    app.update();
+    assert_eq!(logged(&app), vec!["paused", "paused"]);
+}
+
+#[test]
+fn test_fan_out_system_can_be_ordered() {
+    let mut app = log_app();
+    app.add_message::<PlayerState>();
+    app.add_observer(|_: On<player_state::Paused>, mut log: ResMut<Log>| {
+        log.push("paused");
+    });
+    app.add_systems(
+        Update,
+        (
+            |mut writer: MessageWriter<PlayerState>| {
+                writer.write(PlayerState::Paused);
+            },
+            player_state::fan_out,
+        )
+            .chain(),
+    );
+
+    app.update();
+    assert_eq!(logged(&app), vec!["paused"]);
+}
+
+#[derive(Component, Default)]
+struct Health(u32);
+
+#[derive(EnumEntityEvent, Message, Clone, Copy, Debug)]
+#[enum_event(fan_out)]
+#[allow(dead_code)]
+enum PlayerEvent {
+    Damaged { entity: Entity, amount: u32 },
+    Healed { entity: Entity, amount: u32 },
+}
+
+#[test]
+fn test_fan_out_routes_entity_events_to_target() {
+    let mut app = App::new();
+    app.add_plugins(player_event::FanOutPlugin::default());
+
+    let player = app.world_mut().spawn(Health(10)).id();
+    let bystander = app.world_mut().spawn(Health(10)).id();
+    for entity in [player, bystander] {
+        app.world_mut().entity_mut(entity).observe(
+            |event: On<player_event::Damaged>, mut query: Query<&mut Health>| {
+                query.get_mut(event.entity).unwrap().0 -= event.amount;
+            },
+        );
+        app.world_mut().entity_mut(entity).observe(
+            |event: On<player_event::Healed>, mut query: Query<&mut Health>| {
+                query.get_mut(event.entity).unwrap().0 += event.amount;
+            },
+        );
+    }
+
+    app.world_mut().write_message(PlayerEvent::Damaged {
+        entity: player,
+        amount: 4,
+    });
+    app.world_mut().write_message(PlayerEvent::Healed {
+        entity: player,
+        amount: 1,
+    });
+    app.update();
+
+    assert_eq!(app.world().get::<Health>(player).unwrap().0, 7);
+    assert_eq!(app.world().get::<Health>(bystander).unwrap().0, 10);
+}
@@ -0,0 +1,125 @@
+//! Tests for field-level `#[enum_event(default)]` / `#[enum_event(default = expr)]` and
+//! variant-level `#[enum_event(default)]`.
+
+use bevy::prelude::*;
+use bevy_enum_event::{EnumEntityEvent, EnumEvent};
+
+const DEFAULT_VOLUME: f32 = 0.8;
+
+#[derive(EnumEvent, Clone, Debug, PartialEq)]
+#[enum_event(derive(PartialEq))]
+#[allow(dead_code)]
+enum TuningEvent {
+    #[enum_event(default)]
+    AudioChanged {
+        #[enum_event(default = DEFAULT_VOLUME)]
+        volume: f32,
+        muted: bool,
+        #[enum_event(default = "default".to_string())]
+        device: String,
+    },
+    #[enum_event(default)]
+    Zoomed(#[enum_event(default = 1.0)] f32, u32),
+    // Defaulted fields are left out of the constructor
+    Renamed {
+        name: String,
+        #[enum_event(default)]
+        permanent: bool,
+    },
+}
+
+#[test]
+fn test_variant_default_uses_field_defaults() {
+    assert_eq!(
+        tuning_event::AudioChanged::default(),
+        tuning_event::AudioChanged {
+            volume: 0.8,
+            muted: false,
+            device: "default".to_string(),
+        }
+    );
+    let zoomed = tuning_event::Zoomed::default();
+    assert_eq!((zoomed.0, zoomed.1), (1.0, 0));
+}
+
+#[test]
+fn test_constructors_omit_defaulted_fields() {
+    let audio = tuning_event::AudioChanged::new(true);
+    assert_eq!(audio.volume, 0.8);
+    assert!(audio.muted);
+
+    let zoomed = tuning_event::Zoomed::new(3);
+    assert_eq!((zoomed.0, zoomed.1), (1.0, 3));
+
+    let renamed = tuning_event::Renamed::new("Ada".to_string());
+    assert_eq!(renamed.name, "Ada");
+    assert!(!renamed.permanent);
+}
+
+#[derive(EnumEntityEvent, Clone, Copy, Debug)]
+#[allow(dead_code)]
+enum PlayerEvent {
+    // Entity events need a default target to implement `Default`
+    #[enum_event(default)]
+    Reset {
+        #[enum_event(default = Entity::PLACEHOLDER)]
+        entity: Entity,
+        #[enum_event(default = 100)]
+        health: u32,
+    },
+    Damaged {
+        entity: Entity,
+        #[enum_event(default = 1.0)]
+        amount: f32,
+    },
+}
+
+#[test]
+fn test_entity_event_defaults() {
+    let player = Entity::from_bits(1);
+    let damaged = player_event::Damaged::new(player);
+    assert_eq!((damaged.entity, damaged.amount), (player, 1.0));
+
+    let reset = player_event::Reset::default();
+    assert_eq!(reset.entity, Entity::PLACEHOLDER);
+    assert_eq!(reset.health, 100);
+}
+
+#[derive(EnumEvent, Clone, Debug)]
+#[allow(dead_code)]
+enum ValueEvent<T> {
+    // Fields without a value need `Default` themselves
+    #[enum_event(default)]
+    Changed { value: T, count: u32 },
+}
+
+#[test]
+fn test_generic_variant_default() {
+    let changed = value_event::Changed::<String>::default();
+    assert_eq!(changed.value, "");
+    assert_eq!(changed.count, 0);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Team {
+    id: u32,
+}
+
+#[derive(EnumEvent, Clone, Copy, Debug)]
+#[allow(dead_code)]
+enum TeamEvent {
+    // Struct literals in defaults resolve in the enum's scope, not to the generated `Team`
+    #[enum_event(default)]
+    Team(#[enum_event(default = Team { id: 7 })] Team),
+    Scored {
+        #[enum_event(default = { let Team { id } = Team { id: 2 }; Team { id: id + 1 } })]
+        team: Team,
+        points: u32,
+    },
+}
+
+#[test]
+fn test_struct_literal_defaults_resolve_in_enum_scope() {
+    assert_eq!(team_event::Team::default().0, Team { id: 7 });
+    assert_eq!(team_event::Scored::new(5).team, Team { id: 3 });
+}
@@ -0,0 +1,88 @@
+//! Tests for `#[enum_event(flatten)]`, which emits the generated items into the
+//! enum's own scope.
+
+mod common;
+
+use bevy::prelude::*;
+
+use common::{log_app, logged, Log};
+
+/// A dedicated events module holding several flattened enums.
+mod events {
+    use bevy::prelude::*;
+    use bevy_enum_event::{EnumEntityEvent, EnumEvent};
+
+    #[derive(EnumEvent, Clone, Copy, Debug, PartialEq)]
+    #[enum_event(flatten, prefix = "Player", any)]
+    #[allow(dead_code)]
+    pub enum PlayerState {
+        Idle,
+        Running(u32),
+    }
+
+    #[derive(EnumEvent, Clone, Copy, Debug, PartialEq)]
+    #[enum_event(flatten, prefix = "Enemy", any)]
+    #[allow(dead_code)]
+    pub enum EnemyState {
+        Idle,
+    }
+
+    #[derive(Component)]
+    #[relationship(relationship_target = Slots)]
+    pub struct InSlot(pub Entity);
+
+    #[derive(Component)]
+    #[relationship_target(relationship = InSlot)]
+    pub struct Slots(Vec<Entity>);
+
+    // The relationship resolves in this module without any path rewriting
+    #[derive(EnumEntityEvent, Clone, Copy, Debug)]
+    #[enum_event(flatten, suffix = "Event", auto_propagate, propagate = &'static InSlot)]
+    #[allow(dead_code)]
+    pub enum ItemEvent {
+        Used { entity: Entity },
+    }
+}
+
+#[test]
+fn test_flattened_structs_share_a_scope() {
+    let mut app = log_app();
+    app.add_observer(|event: On<events::PlayerRunning>, mut log: ResMut<Log>| {
+        log.push(format!("running {}", event.0));
+    });
+    app.add_observer(|_: On<events::EnemyIdle>, mut log: ResMut<Log>| {
+        log.push("enemy idle");
+    });
+    app.add_observer(|event: On<events::PlayerStateAny>, mut log: ResMut<Log>| {
+        log.push(format!("player {:?}", event.0));
+    });
+
+    events::PlayerState::Running(1).trigger_world(app.world_mut());
+    events::EnemyState::Idle.trigger_world(app.world_mut());
+
+    assert_eq!(
+        logged(&app),
+        vec!["running 1", "player Running(1)", "enemy idle"]
+    );
+    assert_eq!(
+        events::PlayerState::from(events::PlayerIdle),
+        events::PlayerState::Idle
+    );
+}
+
+#[test]
+fn test_flattened_entity_event_propagates() {
+    let mut app = log_app();
+
+    let inventory = app.world_mut().spawn_empty().id();
+    let item = app.world_mut().spawn(events::InSlot(inventory)).id();
+    app.world_mut().entity_mut(inventory).observe(
+        |_: On<events::UsedEvent>, mut log: ResMut<Log>| {
+            log.push("inventory");
+        },
+    );
+
+    events::ItemEvent::Used { entity: item }.trigger_world(app.world_mut());
+
+    assert_eq!(logged(&app), vec!["inventory"]);
+}
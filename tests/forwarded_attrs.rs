@@ -0,0 +1,98 @@
+//! Tests that docs, `cfg`, `deprecated` and lint attributes on variants are
+//! forwarded to the generated structs, and the enum's docs and lints to its module.
+
+#![deny(missing_docs)]
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_enum_event::{EnumEvent, EnumMessage};
+
+use common::{log_app, logged, Log};
+
+/// Game-wide events.
+#[derive(EnumEvent, Clone, Debug, PartialEq)]
+#[enum_event(any)]
+#[allow(dead_code)]
+pub enum GameEvent {
+    /// The match started.
+    Started,
+    /// A compiled-out variant.
+    #[cfg(any())]
+    Disabled(u32),
+    /// A variant that is always compiled.
+    #[cfg(test)]
+    Enabled(u32),
+    /// Replaced by `Started`.
+    #[deprecated(note = "use `Started`")]
+    Legacy,
+    /// A variant whose name breaks the naming convention.
+    #[allow(non_camel_case_types)]
+    legacy_name {
+        /// Payload.
+        value: u32,
+    },
+}
+
+#[test]
+fn test_cfg_variants_dispatch_and_convert() {
+    let mut app = log_app();
+    app.add_observer(|event: On<game_event::Enabled>, mut log: ResMut<Log>| {
+        log.push(format!("enabled {}", event.0));
+    });
+    app.add_observer(|event: On<game_event::Any>, mut log: ResMut<Log>| {
+        log.push(format!("any {:?}", event.0));
+    });
+
+    GameEvent::Enabled(1).trigger_world(app.world_mut());
+
+    assert_eq!(logged(&app), vec!["enabled 1", "any Enabled(1)"]);
+    assert_eq!(
+        GameEvent::from(game_event::Enabled(2)),
+        GameEvent::Enabled(2)
+    );
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_variant_struct() {
+    let mut app = log_app();
+    app.add_observer(|_: On<game_event::Legacy>, mut log: ResMut<Log>| {
+        log.push("legacy");
+    });
+
+    GameEvent::Legacy.trigger_world(app.world_mut());
+
+    assert_eq!(logged(&app), vec!["legacy"]);
+    assert!(game_event::Legacy::try_from(GameEvent::Legacy).is_ok());
+}
+
+#[test]
+fn test_lint_attributes_forwarded() {
+    let event = game_event::legacy_name { value: 3 };
+    assert_eq!(GameEvent::from(event), GameEvent::legacy_name { value: 3 });
+}
+
+/// Buffered input actions.
+#[derive(EnumMessage, Clone, Copy, Debug)]
+#[allow(dead_code)]
+pub enum InputAction {
+    /// Jump once.
+    Jump,
+    /// Only available with an unset cfg.
+    #[cfg(any())]
+    Dash,
+}
+
+#[test]
+fn test_cfg_variants_skip_message_registration() {
+    let mut app = App::new();
+    input_action::add_messages(&mut app);
+
+    InputAction::Jump.write_world(app.world_mut());
+
+    assert_eq!(
+        app.world().resource::<Messages<input_action::Jump>>().len(),
+        1
+    );
+}
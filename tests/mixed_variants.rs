@@ -0,0 +1,242 @@
+//! Tests for enums mixing global and entity-targeted variants in one derive.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_enum_event::{EnumEntityEvent, EnumEvent};
+
+use common::{log_app, logged, Log};
+
+// Variants marked `entity` or with a target field become entity events, the rest stay global
+#[derive(EnumEvent, Clone, Copy, Debug, PartialEq)]
+#[enum_event(any)]
+#[allow(dead_code)]
+enum GameEvent {
+    Started,
+    #[enum_event(entity)]
+    Damaged {
+        entity: Entity,
+        amount: u32,
+    },
+    Healed(#[enum_event(target)] Entity, u32),
+    #[enum_event(entity)]
+    Died(Entity),
+    Spawned {
+        entity: Entity,
+    },
+}
+
+#[test]
+fn test_entity_variants_target_their_entity() {
+    let mut app = log_app();
+    let player = app.world_mut().spawn_empty().id();
+    let other = app.world_mut().spawn_empty().id();
+    app.world_mut().entity_mut(player).observe(
+        |event: On<game_event::Damaged>, mut log: ResMut<Log>| {
+            log.push(format!("damaged {}", event.amount));
+        },
+    );
+    app.world_mut().entity_mut(player).observe(
+        |event: On<game_event::Healed>, mut log: ResMut<Log>| {
+            log.push(format!("healed {}", event.1));
+        },
+    );
+    app.world_mut()
+        .entity_mut(other)
+        .observe(|_: On<game_event::Died>, mut log: ResMut<Log>| {
+            log.push("other died");
+        });
+
+    GameEvent::Damaged {
+        entity: player,
+        amount: 3,
+    }
+    .trigger_world(app.world_mut());
+    GameEvent::Healed(player, 2).trigger_world(app.world_mut());
+    GameEvent::Died(player).trigger_world(app.world_mut());
+
+    assert_eq!(logged(&app), vec!["damaged 3", "healed 2"]);
+    assert_eq!(
+        game_event::Damaged {
+            entity: player,
+            amount: 1
+        }
+        .event_target(),
+        player
+    );
+}
+
+// A field named `entity` does not make the variant an entity event on its own
+#[derive(EnumEvent, Clone, Copy, Debug)]
+#[allow(dead_code)]
+enum ScoreEvent {
+    Scored { entity: u32, points: u32 },
+}
+
+mod domain {
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct Entity(pub u32);
+}
+
+#[derive(EnumEvent, Clone, Copy, Debug)]
+#[allow(dead_code)]
+enum SaveEvent {
+    Created { entity: domain::Entity },
+}
+
+#[derive(EnumEvent, Clone, Copy, Debug)]
+#[enum_event(fields(entity: u32))]
+#[allow(dead_code)]
+enum TeamEvent {
+    Joined,
+}
+
+#[test]
+fn test_unmarked_entity_fields_stay_global() {
+    let mut app = log_app();
+    app.add_observer(|event: On<score_event::Scored>, mut log: ResMut<Log>| {
+        log.push(format!("scored {} {}", event.entity, event.points));
+    });
+    app.add_observer(|event: On<team_event::Joined>, mut log: ResMut<Log>| {
+        log.push(format!("joined {}", event.entity));
+    });
+
+    ScoreEvent::Scored {
+        entity: 7,
+        points: 3,
+    }
+    .trigger_world(app.world_mut());
+
+    TeamEvent::Joined.trigger_world(app.world_mut(), 2);
+
+    app.add_observer(|event: On<save_event::Created>, mut log: ResMut<Log>| {
+        log.push(format!("created {}", event.entity.0));
+    });
+    SaveEvent::Created {
+        entity: domain::Entity(4),
+    }
+    .trigger_world(app.world_mut());
+
+    assert_eq!(logged(&app), vec!["scored 7 3", "joined 2", "created 4"]);
+}
+
+#[test]
+fn test_global_variants_reach_global_observers() {
+    let mut app = log_app();
+    app.add_observer(|_: On<game_event::Started>, mut log: ResMut<Log>| {
+        log.push("started");
+    });
+    app.add_observer(|event: On<game_event::Spawned>, mut log: ResMut<Log>| {
+        log.push(format!("spawned {:?}", event.entity));
+    });
+    app.add_observer(|event: On<game_event::Any>, mut log: ResMut<Log>| {
+        log.push(format!("any {:?}", event.0));
+    });
+
+    let player = app.world_mut().spawn_empty().id();
+    GameEvent::Started.trigger_world(app.world_mut());
+    GameEvent::Spawned { entity: player }.trigger_world(app.world_mut());
+
+    assert_eq!(
+        logged(&app),
+        vec![
+            "started".to_string(),
+            "any Started".to_string(),
+            format!("spawned {player:?}"),
+            format!("any Spawned {{ entity: {player:?} }}"),
+        ]
+    );
+}
+
+// `EnumEntityEvent` opts individual variants out with `global`
+#[derive(EnumEntityEvent, Clone, Copy, Debug)]
+#[enum_event(auto_propagate, propagate)]
+#[allow(dead_code)]
+enum UiEvent {
+    Clicked {
+        entity: Entity,
+    },
+    #[enum_event(global)]
+    FocusCleared,
+}
+
+#[test]
+fn test_entity_derive_with_global_variant() {
+    let mut app = log_app();
+    let parent = app.world_mut().spawn_empty().id();
+    let child = app.world_mut().spawn(ChildOf(parent)).id();
+    app.world_mut()
+        .entity_mut(parent)
+        .observe(|_: On<ui_event::Clicked>, mut log: ResMut<Log>| {
+            log.push("parent clicked");
+        });
+    app.add_observer(|_: On<ui_event::FocusCleared>, mut log: ResMut<Log>| {
+        log.push("focus cleared");
+    });
+
+    UiEvent::Clicked { entity: child }.trigger_world(app.world_mut());
+    UiEvent::FocusCleared.trigger_world(app.world_mut());
+
+    assert_eq!(logged(&app), vec!["parent clicked", "focus cleared"]);
+}
+
+// Enum-level propagation applies to the entity variants of an `EnumEvent`
+#[derive(EnumEvent, Clone, Copy, Debug)]
+#[enum_event(auto_propagate, propagate)]
+#[allow(dead_code)]
+enum MenuEvent {
+    Opened,
+    #[enum_event(entity)]
+    Selected {
+        entity: Entity,
+    },
+}
+
+#[test]
+fn test_enum_level_propagate_on_mixed_enum() {
+    let mut app = log_app();
+    let parent = app.world_mut().spawn_empty().id();
+    let child = app.world_mut().spawn(ChildOf(parent)).id();
+    app.world_mut().entity_mut(parent).observe(
+        |_: On<menu_event::Selected>, mut log: ResMut<Log>| {
+            log.push("parent selected");
+        },
+    );
+    app.add_observer(|_: On<menu_event::Opened>, mut log: ResMut<Log>| {
+        log.push("opened");
+    });
+
+    MenuEvent::Opened.trigger_world(app.world_mut());
+    MenuEvent::Selected { entity: child }.trigger_world(app.world_mut());
+
+    assert_eq!(logged(&app), vec!["opened", "parent selected"]);
+}
+
+// A shared field marked as the target opts in every variant not marked `global`
+#[derive(EnumEvent, Clone, Copy, Debug)]
+#[enum_event(fields(#[enum_event(target)] owner: Entity))]
+#[allow(dead_code)]
+enum ItemEvent {
+    Picked,
+    #[enum_event(global)]
+    Listed,
+}
+
+#[test]
+fn test_shared_target_opts_in_every_variant() {
+    let mut app = log_app();
+    let owner = app.world_mut().spawn_empty().id();
+    app.world_mut()
+        .entity_mut(owner)
+        .observe(|_: On<item_event::Picked>, mut log: ResMut<Log>| {
+            log.push("picked");
+        });
+    app.add_observer(|_: On<item_event::Listed>, mut log: ResMut<Log>| {
+        log.push("listed");
+    });
+
+    ItemEvent::Picked.trigger_world(app.world_mut(), owner);
+    ItemEvent::Listed.trigger_world(app.world_mut(), owner);
+
+    assert_eq!(logged(&app), vec!["picked", "listed"]);
+}
@@ -0,0 +1,165 @@
+//! Tests for `#[enum_event(module = "...")]` and `#[enum_event(module = self)]`,
+//! which control the name and placement of the generated items.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_enum_event::{EnumEvent, EnumMessage};
+
+use common::{log_app, logged, Log};
+
+// Both names convert to `http_event`, so one of them picks another module
+#[derive(EnumEvent, Clone, Copy, Debug)]
+#[allow(dead_code, clippy::upper_case_acronyms)]
+enum HTTPEvent {
+    Request,
+}
+
+#[derive(EnumEvent, Clone, Copy, Debug)]
+#[enum_event(module = "http_v2")]
+#[allow(dead_code)]
+enum HttpEvent {
+    Request,
+    Response(u16),
+}
+
+#[test]
+fn test_colliding_module_names_can_be_renamed() {
+    let _ = http_event::Request;
+    let response = http_v2::Response(200);
+    assert_eq!(response.0, 200);
+    assert!(matches!(
+        HttpEvent::from(http_v2::Request),
+        HttpEvent::Request
+    ));
+}
+
+/// Events from several enums grouped under one parent module.
+mod events {
+    use bevy::prelude::*;
+    use bevy_enum_event::{EnumEntityEvent, EnumEvent};
+
+    #[derive(EnumEvent, Clone, Copy, Debug)]
+    #[enum_event(module = "game")]
+    #[allow(dead_code)]
+    pub enum GameEvent {
+        Started,
+    }
+
+    #[derive(EnumEntityEvent, Clone, Copy, Debug)]
+    #[enum_event(module = "player")]
+    #[allow(dead_code)]
+    pub enum PlayerEvent {
+        Damaged { entity: Entity, amount: u32 },
+    }
+}
+
+#[test]
+fn test_modules_nested_under_common_parent() {
+    let mut app = log_app();
+    app.add_observer(|_: On<events::game::Started>, mut log: ResMut<Log>| {
+        log.push("started");
+    });
+    app.add_observer(|event: On<events::player::Damaged>, mut log: ResMut<Log>| {
+        log.push(format!("damaged {}", event.amount));
+    });
+
+    let player = app.world_mut().spawn_empty().id();
+    events::GameEvent::Started.trigger_world(app.world_mut());
+    events::PlayerEvent::Damaged {
+        entity: player,
+        amount: 3,
+    }
+    .trigger_world(app.world_mut());
+
+    assert_eq!(logged(&app), vec!["started", "damaged 3"]);
+}
+
+/// Items generated with `module = self` live next to their enums.
+mod flat {
+    use bevy::prelude::*;
+    use bevy_enum_event::{EnumEntityEvent, EnumEvent};
+
+    #[derive(EnumEvent, Clone, Copy, Debug, PartialEq)]
+    #[enum_event(module = self, any)]
+    #[allow(dead_code)]
+    pub enum PlayerState {
+        PlayerIdle,
+        PlayerRunning(u32),
+    }
+
+    #[derive(EnumEvent, Clone, Copy, Debug, PartialEq)]
+    #[enum_event(module = self, any)]
+    #[allow(dead_code)]
+    pub enum EnemyState {
+        EnemyIdle,
+    }
+
+    #[derive(EnumEntityEvent, Clone, Copy, Debug)]
+    #[enum_event(module = self, auto_propagate, propagate = &'static ChildOf)]
+    #[allow(dead_code)]
+    pub enum UiEvent {
+        Clicked { entity: Entity },
+    }
+}
+
+#[test]
+fn test_module_self_places_items_next_to_enum() {
+    let mut app = log_app();
+    app.add_observer(|event: On<flat::PlayerRunning>, mut log: ResMut<Log>| {
+        log.push(format!("running {}", event.0));
+    });
+    // Auxiliary items are prefixed with the enum name so several enums can share a scope
+    app.add_observer(|event: On<flat::PlayerStateAny>, mut log: ResMut<Log>| {
+        log.push(format!("player {:?}", event.0));
+    });
+    app.add_observer(|event: On<flat::EnemyStateAny>, mut log: ResMut<Log>| {
+        log.push(format!("enemy {:?}", event.0));
+    });
+
+    flat::PlayerState::PlayerRunning(2).trigger_world(app.world_mut());
+    flat::EnemyState::EnemyIdle.trigger_world(app.world_mut());
+
+    assert_eq!(
+        logged(&app),
+        vec!["running 2", "player PlayerRunning(2)", "enemy EnemyIdle"]
+    );
+    assert_eq!(
+        flat::PlayerState::from(flat::PlayerIdle),
+        flat::PlayerState::PlayerIdle
+    );
+}
+
+#[test]
+fn test_module_self_propagates_with_custom_relationship() {
+    let mut app = log_app();
+
+    let parent = app.world_mut().spawn_empty().id();
+    let child = app.world_mut().spawn(ChildOf(parent)).id();
+    app.world_mut()
+        .entity_mut(parent)
+        .observe(|_: On<flat::Clicked>, mut log: ResMut<Log>| {
+            log.push("parent");
+        });
+
+    flat::UiEvent::Clicked { entity: child }.trigger_world(app.world_mut());
+
+    assert_eq!(logged(&app), vec!["parent"]);
+}
+
+#[derive(EnumMessage, Clone, Copy, Debug)]
+#[enum_event(module = self)]
+#[allow(dead_code)]
+enum InputAction {
+    Jump,
+}
+
+#[test]
+fn test_module_self_message_registration() {
+    let mut app = App::new();
+    input_action_add_messages(&mut app);
+
+    InputAction::Jump.write_world(app.world_mut());
+
+    assert_eq!(app.world().resource::<Messages<Jump>>().len(), 1);
+}
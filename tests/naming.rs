@@ -0,0 +1,114 @@
+//! Tests for `#[enum_event(rename = "...")]`, `prefix` and `suffix`, which
+//! control the names of the generated structs.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_enum_event::{EnumEntityEvent, EnumEvent, EnumMessage};
+
+use common::{log_app, logged, Log};
+
+#[derive(EnumEvent, Clone, Copy, Debug, PartialEq)]
+#[enum_event(suffix = "Event", any)]
+#[allow(dead_code)]
+enum PlayerState {
+    Idle,
+    Running(u32),
+    #[enum_event(rename = "PlayerJumped")]
+    Jumping {
+        height: u32,
+    },
+}
+
+#[derive(EnumEvent, Clone, Copy, Debug, PartialEq)]
+#[enum_event(prefix = "Enemy")]
+#[allow(dead_code)]
+enum EnemyState {
+    Idle,
+}
+
+// Generated names no longer clash, so both can be imported side by side
+use enemy_state::EnemyIdle;
+use player_state::IdleEvent;
+
+#[test]
+fn test_suffix_and_prefix_applied() {
+    let mut app = log_app();
+    app.add_observer(|_: On<IdleEvent>, mut log: ResMut<Log>| {
+        log.push("player idle");
+    });
+    app.add_observer(|_: On<EnemyIdle>, mut log: ResMut<Log>| {
+        log.push("enemy idle");
+    });
+    app.add_observer(
+        |event: On<player_state::RunningEvent>, mut log: ResMut<Log>| {
+            log.push(format!("running {}", event.0));
+        },
+    );
+
+    PlayerState::Idle.trigger_world(app.world_mut());
+    EnemyState::Idle.trigger_world(app.world_mut());
+    PlayerState::Running(3).trigger_world(app.world_mut());
+
+    assert_eq!(logged(&app), vec!["player idle", "enemy idle", "running 3"]);
+}
+
+#[test]
+fn test_rename_overrides_affixes() {
+    let jumped = player_state::PlayerJumped { height: 2 };
+    assert_eq!(
+        PlayerState::from(jumped),
+        PlayerState::Jumping { height: 2 }
+    );
+    let back: player_state::PlayerJumped = PlayerState::Jumping { height: 4 }.try_into().unwrap();
+    assert_eq!(back.height, 4);
+    // Auxiliary items keep their names
+    let _ = player_state::Any(PlayerState::Idle);
+}
+
+// A variant renamed after its own payload type still resolves the payload in the enum's scope
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Hit(u32);
+
+#[derive(EnumEntityEvent, Clone, Copy, Debug)]
+#[allow(dead_code)]
+enum CombatEvent {
+    #[enum_event(rename = "Hit")]
+    Struck { entity: Entity, hit: Hit },
+}
+
+#[test]
+fn test_renamed_entity_event() {
+    let mut app = log_app();
+    let target = app.world_mut().spawn_empty().id();
+    app.world_mut().entity_mut(target).observe(
+        |event: On<combat_event::Hit>, mut log: ResMut<Log>| {
+            log.push(format!("hit {}", event.hit.0));
+        },
+    );
+
+    CombatEvent::Struck {
+        entity: target,
+        hit: Hit(7),
+    }
+    .trigger_world(app.world_mut());
+
+    assert_eq!(logged(&app), vec!["hit 7"]);
+}
+
+#[derive(EnumMessage, Clone, Copy, Debug)]
+#[enum_event(module = self, suffix = "Action")]
+#[allow(dead_code)]
+enum InputAction {
+    Jump,
+}
+
+#[test]
+fn test_suffix_with_flat_items() {
+    let mut app = App::new();
+    input_action_add_messages(&mut app);
+
+    InputAction::Jump.write_world(app.world_mut());
+
+    assert_eq!(app.world().resource::<Messages<JumpAction>>().len(), 1);
+}
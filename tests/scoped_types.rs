@@ -0,0 +1,214 @@
+//! Tests that field types, generic bounds and where clauses resolve in the
+//! enum's scope rather than inside the generated module.
+
+use bevy::prelude::*;
+use bevy_enum_event::{EnumEntityEvent, EnumEvent};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Team(u8);
+
+#[derive(Clone, Debug, PartialEq)]
+struct Roster {
+    names: Vec<String>,
+}
+
+#[derive(EnumEvent, Clone, Debug, PartialEq)]
+#[allow(dead_code)]
+enum MatchEvent {
+    ScoreChanged { team: Team, score: i32 },
+    Announced(String),
+    Lineup(Option<Roster>),
+}
+
+#[test]
+fn test_local_field_types_resolve() {
+    let score = match_event::ScoreChanged {
+        team: Team(1),
+        score: 3,
+    };
+    assert_eq!(score.team, Team(1));
+
+    let announced = match_event::Announced("kickoff".to_string());
+    assert_eq!(announced.0, "kickoff");
+
+    let lineup = match_event::Lineup(Some(Roster {
+        names: vec!["ada".to_string()],
+    }));
+    assert_eq!(
+        MatchEvent::from(lineup),
+        MatchEvent::Lineup(Some(Roster {
+            names: vec!["ada".to_string()],
+        }))
+    );
+}
+
+// Variants named like the payload types they carry
+#[derive(EnumEvent, Clone, Debug, PartialEq)]
+#[allow(dead_code)]
+enum TeamEvent {
+    Team(Team),
+    Roster { roster: Roster, teams: Vec<Team> },
+}
+
+#[test]
+fn test_payload_types_shadowed_by_generated_structs() {
+    let team: team_event::Team = TeamEvent::Team(Team(4)).try_into().unwrap();
+    assert_eq!(team.0, Team(4));
+
+    let roster = team_event::Roster {
+        roster: Roster { names: Vec::new() },
+        teams: vec![Team(1), Team(2)],
+    };
+    assert_eq!(roster.teams, vec![Team(1), Team(2)]);
+}
+
+trait Scorer: Clone + Send + Sync + 'static {
+    fn points(&self) -> u32;
+}
+
+trait Labeled {
+    fn label(&self) -> &'static str;
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Goal;
+
+impl Scorer for Goal {
+    fn points(&self) -> u32 {
+        3
+    }
+}
+
+impl Labeled for Goal {
+    fn label(&self) -> &'static str {
+        "goal"
+    }
+}
+
+#[derive(EnumEvent, Clone, Debug)]
+#[allow(dead_code)]
+enum ScoreEvent<S: Scorer>
+where
+    S: Labeled,
+{
+    Scored(S),
+    Labeled { scorer: S, team: Team },
+}
+
+#[test]
+fn test_generic_bounds_resolve() {
+    let mut app = App::new();
+    app.add_observer(|event: On<score_event::Scored<Goal>>| {
+        assert_eq!(event.0.points(), 3);
+    });
+
+    ScoreEvent::Scored(Goal).trigger_world(app.world_mut());
+
+    let labeled = score_event::Labeled {
+        scorer: Goal,
+        team: Team(2),
+    };
+    assert_eq!(labeled.scorer.label(), "goal");
+}
+
+#[derive(Component)]
+struct Health(u32);
+
+#[derive(EnumEntityEvent, Clone, Copy, Debug)]
+#[allow(dead_code)]
+enum PlayerEvent {
+    Damaged { entity: Entity, team: Team },
+}
+
+#[test]
+fn test_entity_event_field_types_resolve() {
+    let mut app = App::new();
+    let player = app.world_mut().spawn(Health(5)).id();
+    app.world_mut().entity_mut(player).observe(
+        |event: On<player_event::Damaged>, mut query: Query<&mut Health>| {
+            query.get_mut(event.entity).unwrap().0 -= u32::from(event.team.0);
+        },
+    );
+
+    PlayerEvent::Damaged {
+        entity: player,
+        team: Team(2),
+    }
+    .trigger_world(app.world_mut());
+
+    assert_eq!(app.world().get::<Health>(player).unwrap().0, 3);
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Weight(u32);
+
+#[derive(Component)]
+#[relationship(relationship_target = Carried)]
+pub struct CarriedBy(pub Entity);
+
+#[derive(Component)]
+#[relationship_target(relationship = CarriedBy)]
+pub struct Carried(Vec<Entity>);
+
+/// Paths relative to the enum's module resolve as they do next to the enum.
+mod cargo {
+    use bevy::prelude::*;
+    use bevy_enum_event::{EnumEntityEvent, EnumEvent};
+
+    #[derive(EnumEvent, Clone, Debug)]
+    #[allow(dead_code)]
+    pub enum LoadEvent<S: super::Scorer>
+    where
+        S: super::Labeled,
+    {
+        Loaded {
+            weight: super::Weight,
+            #[enum_event(default = super::Weight(2))]
+            extra: super::Weight,
+        },
+        Scored(S, self::Crate),
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct Crate;
+
+    #[derive(EnumEntityEvent, Clone, Copy, Debug)]
+    #[enum_event(auto_propagate, propagate = &'static super::CarriedBy)]
+    #[allow(dead_code)]
+    pub enum DropEvent {
+        Dropped {
+            entity: Entity,
+            weight: super::Weight,
+        },
+    }
+}
+
+#[test]
+fn test_relative_paths_resolve_from_the_enums_module() {
+    let loaded = cargo::load_event::Loaded::<Goal>::new(Weight(1));
+    assert_eq!((loaded.weight, loaded.extra), (Weight(1), Weight(2)));
+
+    let scored: cargo::load_event::Scored<Goal> = cargo::LoadEvent::Scored(Goal, cargo::Crate)
+        .try_into()
+        .unwrap();
+    assert_eq!(scored.1, cargo::Crate);
+
+    let mut app = App::new();
+    let truck = app.world_mut().spawn(Health(10)).id();
+    let parcel = app.world_mut().spawn(CarriedBy(truck)).id();
+    app.world_mut().entity_mut(truck).observe(
+        |event: On<cargo::drop_event::Dropped>, mut query: Query<&mut Health>| {
+            if let Ok(mut health) = query.get_mut(event.entity) {
+                health.0 -= event.weight.0;
+            }
+        },
+    );
+
+    cargo::DropEvent::Dropped {
+        entity: parcel,
+        weight: Weight(4),
+    }
+    .trigger_world(app.world_mut());
+
+    assert_eq!(app.world().get::<Health>(truck).unwrap().0, 6);
+}
@@ -0,0 +1,99 @@
+//! Tests for `#[enum_event(fields(...))]`, which injects shared fields into every
+//! generated struct.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_enum_event::{EnumEntityEvent, EnumEvent};
+
+use common::{log_app, logged, Log};
+
+// Unit variants become entity events through the shared target field
+#[derive(EnumEntityEvent, Clone, Copy, Debug, PartialEq)]
+#[enum_event(fields(entity: Entity, timestamp: f64), any)]
+#[allow(dead_code)]
+enum DoorEvent {
+    Opened,
+    Closed,
+    Locked { code: u32 },
+}
+
+#[test]
+fn test_shared_fields_target_unit_variants() {
+    let mut app = log_app();
+    let door = app.world_mut().spawn_empty().id();
+    app.world_mut().entity_mut(door).observe(
+        |event: On<door_event::Opened>, mut log: ResMut<Log>| {
+            log.push(format!("opened at {}", event.timestamp));
+        },
+    );
+    app.world_mut().entity_mut(door).observe(
+        |event: On<door_event::Locked>, mut log: ResMut<Log>| {
+            log.push(format!("locked with {}", event.code));
+        },
+    );
+    app.world_mut()
+        .entity_mut(door)
+        .observe(|event: On<door_event::Any>, mut log: ResMut<Log>| {
+            log.push(format!("any {:?}", event.value));
+        });
+
+    DoorEvent::Opened.trigger_world(app.world_mut(), door, 1.5);
+    DoorEvent::Locked { code: 42 }.trigger_world(app.world_mut(), door, 2.0);
+
+    assert_eq!(
+        logged(&app),
+        vec![
+            "opened at 1.5",
+            "any Opened",
+            "locked with 42",
+            "any Locked { code: 42 }",
+        ]
+    );
+}
+
+#[test]
+fn test_shared_fields_in_constructors_and_conversions() {
+    let entity = Entity::from_bits(3);
+    let locked = door_event::Locked::new(entity, 0.5, 7);
+    assert_eq!(locked.entity, entity);
+    assert_eq!(locked.timestamp, 0.5);
+    assert_eq!(locked.code, 7);
+
+    // Converting back drops the shared fields
+    assert_eq!(DoorEvent::from(locked), DoorEvent::Locked { code: 7 });
+
+    let opened = door_event::Opened::try_from_enum(DoorEvent::Opened, entity, 1.0).unwrap();
+    assert_eq!(opened.timestamp, 1.0);
+    assert_eq!(
+        door_event::Opened::try_from_enum(DoorEvent::Closed, entity, 1.0).unwrap_err(),
+        DoorEvent::Closed
+    );
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Tick(u64);
+
+#[derive(EnumEvent, Clone, Copy, Debug)]
+#[enum_event(event, message, fields(tick: Tick))]
+#[allow(dead_code)]
+enum ScoreEvent {
+    Reset,
+    Scored { points: u32 },
+}
+
+#[test]
+fn test_shared_fields_on_global_events_and_messages() {
+    let mut app = log_app();
+    score_event::add_messages(&mut app);
+    app.add_observer(|event: On<score_event::Scored>, mut log: ResMut<Log>| {
+        log.push(format!("{} at {:?}", event.points, event.tick));
+    });
+
+    ScoreEvent::Scored { points: 3 }.trigger_and_write_world(app.world_mut(), Tick(9));
+
+    assert_eq!(logged(&app), vec!["3 at Tick(9)"]);
+    let messages = app.world().resource::<Messages<score_event::Scored>>();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(score_event::Reset::new(Tick(1)).tick, Tick(1));
+}
@@ -0,0 +1,122 @@
+//! Tests for `#[enum_event(skip)]`, which excludes variants from code generation.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_enum_event::{EnumEntityEvent, EnumEvent, EnumMessage};
+
+use common::{log_app, logged, Log};
+
+// A payload that is neither `Clone` nor `Debug` only appears in the skipped variant
+struct Handle;
+
+#[derive(EnumEvent)]
+#[allow(dead_code)]
+enum GameEvent {
+    Started,
+    Scored(u32),
+    #[enum_event(skip)]
+    Internal(Handle),
+}
+
+#[test]
+fn test_skipped_variant_is_handed_back() {
+    let mut app = log_app();
+    app.add_observer(|event: On<game_event::Scored>, mut log: ResMut<Log>| {
+        log.push(format!("scored {}", event.0));
+    });
+
+    assert!(matches!(
+        GameEvent::Internal(Handle).trigger_world(app.world_mut()),
+        Err(GameEvent::Internal(Handle))
+    ));
+    assert!(GameEvent::Scored(2).trigger_world(app.world_mut()).is_ok());
+
+    assert_eq!(logged(&app), vec!["scored 2"]);
+}
+
+#[test]
+fn test_skipped_variant_fails_conversion() {
+    assert!(game_event::Started::try_from(GameEvent::Internal(Handle)).is_err());
+    assert!(matches!(
+        GameEvent::from(game_event::Scored(1)),
+        GameEvent::Scored(1)
+    ));
+}
+
+#[derive(EnumEntityEvent, Clone, Copy, Debug)]
+#[enum_event(any)]
+#[allow(dead_code)]
+enum PlayerEvent {
+    Damaged {
+        entity: Entity,
+        amount: u32,
+    },
+    // No entity field is needed for skipped variants
+    #[enum_event(skip)]
+    Reset,
+}
+
+#[test]
+fn test_skipped_entity_variant_bypasses_any() {
+    let mut app = log_app();
+    app.add_observer(|event: On<player_event::Any>, mut log: ResMut<Log>| {
+        log.push(format!("any {:?}", event.value));
+    });
+
+    let player = app.world_mut().spawn_empty().id();
+    assert!(matches!(
+        PlayerEvent::Reset.trigger_world(app.world_mut()),
+        Err(PlayerEvent::Reset)
+    ));
+    PlayerEvent::Damaged {
+        entity: player,
+        amount: 4,
+    }
+    .trigger_world(app.world_mut())
+    .unwrap();
+
+    assert_eq!(logged(&app).len(), 1);
+    assert!(logged(&app)[0].starts_with("any Damaged"));
+}
+
+#[derive(EnumMessage, Message, Clone, Copy, Debug)]
+#[enum_event(event, fan_out)]
+#[allow(dead_code)]
+enum InputAction {
+    Jump,
+    #[enum_event(skip)]
+    Debug,
+}
+
+#[test]
+fn test_skipped_variant_is_not_written() {
+    let mut app = App::new();
+    input_action::add_messages(&mut app);
+
+    assert!(InputAction::Debug.write_world(app.world_mut()).is_err());
+    assert!(InputAction::Debug
+        .trigger_and_write_world(app.world_mut())
+        .is_err());
+    InputAction::Jump.write_world(app.world_mut()).unwrap();
+
+    assert_eq!(
+        app.world().resource::<Messages<input_action::Jump>>().len(),
+        1
+    );
+}
+
+#[test]
+fn test_fan_out_drops_skipped_messages() {
+    let mut app = log_app();
+    app.add_plugins(input_action::FanOutPlugin::default());
+    app.add_observer(|_: On<input_action::Jump>, mut log: ResMut<Log>| {
+        log.push("jump");
+    });
+
+    app.world_mut().write_message(InputAction::Debug);
+    app.world_mut().write_message(InputAction::Jump);
+    app.update();
+
+    assert_eq!(logged(&app), vec!["jump"]);
+}
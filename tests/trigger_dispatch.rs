@@ -0,0 +1,136 @@
+//! Tests for the generated `trigger` / `trigger_world` dispatch methods
+//! that route an enum value to its per-variant event.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_enum_event::{EnumEntityEvent, EnumEvent};
+
+use common::{log_app, logged, Log};
+
+#[derive(EnumEvent, Clone, Debug)]
+#[allow(dead_code)]
+enum GameEvent {
+    Started,
+    Victory(u32),
+    ScoreChanged { team: u32, score: i32 },
+}
+
+fn game_event_app() -> App {
+    let mut app = log_app();
+    app.add_observer(|_: On<game_event::Started>, mut log: ResMut<Log>| {
+        log.push("started");
+    });
+    app.add_observer(|event: On<game_event::Victory>, mut log: ResMut<Log>| {
+        log.push(format!("victory {}", event.0));
+    });
+    app.add_observer(
+        |event: On<game_event::ScoreChanged>, mut log: ResMut<Log>| {
+            log.push(format!("score {} {}", event.team, event.score));
+        },
+    );
+    app
+}
+
+#[test]
+fn test_trigger_world_dispatches_each_variant() {
+    let mut app = game_event_app();
+
+    GameEvent::Started.trigger_world(app.world_mut());
+    GameEvent::Victory(3).trigger_world(app.world_mut());
+    GameEvent::ScoreChanged { team: 2, score: 7 }.trigger_world(app.world_mut());
+
+    assert_eq!(logged(&app), vec!["started", "victory 3", "score 2 7"]);
+}
+
+#[test]
+fn test_trigger_via_commands() {
+    let mut app = game_event_app();
+
+    app.add_systems(Update, |mut commands: Commands| {
+        GameEvent::ScoreChanged { team: 1, score: 3 }.trigger(&mut commands);
+        GameEvent::Started.trigger(&mut commands);
+    });
+    app.update();
+
+    assert_eq!(logged(&app), vec!["score 1 3", "started"]);
+}
+
+#[derive(EnumEvent, Clone, Debug)]
+#[allow(dead_code)]
+enum GenericEvent<T>
+where
+    T: Clone + std::fmt::Debug,
+{
+    Owned(T),
+    Unit,
+}
+
+#[test]
+fn test_trigger_generic_enum() {
+    let mut app = log_app();
+    app.add_observer(
+        |event: On<generic_event::Owned<u32>>, mut log: ResMut<Log>| {
+            log.push(format!("owned {}", event.0));
+        },
+    );
+    app.add_observer(|_: On<generic_event::Unit<u32>>, mut log: ResMut<Log>| {
+        log.push("unit");
+    });
+
+    GenericEvent::Owned(5_u32).trigger_world(app.world_mut());
+    GenericEvent::<u32>::Unit.trigger_world(app.world_mut());
+
+    assert_eq!(logged(&app), vec!["owned 5", "unit"]);
+}
+
+#[derive(Component, Default)]
+struct Health(f32);
+
+#[derive(EnumEntityEvent, Clone, Copy)]
+#[allow(dead_code)]
+enum PlayerEvent {
+    Damaged { entity: Entity, amount: f32 },
+    Healed { entity: Entity, amount: f32 },
+}
+
+#[test]
+fn test_trigger_entity_event_targets_entity() {
+    let mut app = App::new();
+
+    let player = app.world_mut().spawn(Health(10.0)).id();
+    let bystander = app.world_mut().spawn(Health(10.0)).id();
+
+    for entity in [player, bystander] {
+        app.world_mut().entity_mut(entity).observe(
+            |event: On<player_event::Damaged>, mut query: Query<&mut Health>| {
+                query.get_mut(event.entity).unwrap().0 -= event.amount;
+            },
+        );
+        app.world_mut().entity_mut(entity).observe(
+            |event: On<player_event::Healed>, mut query: Query<&mut Health>| {
+                query.get_mut(event.entity).unwrap().0 += event.amount;
+            },
+        );
+    }
+
+    PlayerEvent::Damaged {
+        entity: player,
+        amount: 4.0,
+    }
+    .trigger_world(app.world_mut());
+
+    app.add_systems(Update, move |mut commands: Commands| {
+        PlayerEvent::Healed {
+            entity: player,
+            amount: 1.0,
+        }
+        .trigger(&mut commands);
+    });
+    app.update();
+
+    let health = app.world().get::<Health>(player).unwrap().0;
+    assert!((health - 7.0).abs() < f32::EPSILON);
+    let untouched = app.world().get::<Health>(bystander).unwrap().0;
+    assert!((untouched - 10.0).abs() < f32::EPSILON);
+}
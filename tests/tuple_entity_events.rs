@@ -0,0 +1,109 @@
+//! Tests for tuple variants in `EnumEntityEvent`, targeting a marked field or
+//! their only `Entity` field.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_enum_event::EnumEntityEvent;
+
+use common::{log_app, logged, Log};
+
+#[derive(Component)]
+struct Health(f32);
+
+#[derive(EnumEntityEvent, Clone, Copy, Debug, PartialEq)]
+#[enum_event(any)]
+#[allow(dead_code)]
+enum CombatEvent {
+    // The only `Entity` field is the target
+    Damaged(Entity, f32),
+    Healed(f32, Entity),
+    // Several entities need an explicit target
+    Attacked(Entity, #[enum_event(target)] Entity),
+    Died(Entity),
+}
+
+#[test]
+fn test_single_entity_field_is_the_target() {
+    let mut app = App::new();
+    let player = app.world_mut().spawn(Health(10.0)).id();
+    app.world_mut().entity_mut(player).observe(
+        |event: On<combat_event::Damaged>, mut query: Query<&mut Health>| {
+            query.get_mut(event.0).unwrap().0 -= event.1;
+        },
+    );
+    app.world_mut().entity_mut(player).observe(
+        |event: On<combat_event::Healed>, mut query: Query<&mut Health>| {
+            query.get_mut(event.1).unwrap().0 += event.0;
+        },
+    );
+
+    CombatEvent::Damaged(player, 4.0).trigger_world(app.world_mut());
+    CombatEvent::Healed(1.0, player).trigger_world(app.world_mut());
+
+    assert_eq!(app.world().get::<Health>(player).unwrap().0, 7.0);
+}
+
+#[test]
+fn test_marked_tuple_field_is_the_target() {
+    let mut app = log_app();
+    let attacker = app.world_mut().spawn_empty().id();
+    let defender = app.world_mut().spawn_empty().id();
+    app.world_mut().entity_mut(defender).observe(
+        |event: On<combat_event::Attacked>, mut log: ResMut<Log>| {
+            assert_eq!(event.event_target(), event.1);
+            log.push("defender");
+        },
+    );
+    app.world_mut().entity_mut(attacker).observe(
+        |_: On<combat_event::Attacked>, mut log: ResMut<Log>| {
+            log.push("attacker");
+        },
+    );
+
+    CombatEvent::Attacked(attacker, defender).trigger_world(app.world_mut());
+
+    assert_eq!(logged(&app), vec!["defender"]);
+}
+
+#[test]
+fn test_tuple_variants_target_any_event() {
+    let mut app = log_app();
+    let player = app.world_mut().spawn_empty().id();
+    app.world_mut().entity_mut(player).observe(
+        |event: On<combat_event::Any>, mut log: ResMut<Log>| {
+            log.push(format!("{:?}", event.value));
+        },
+    );
+
+    CombatEvent::Died(player).trigger_world(app.world_mut());
+
+    assert_eq!(logged(&app), vec![format!("Died({player:?})")]);
+    assert_eq!(
+        CombatEvent::from(combat_event::Died(player)),
+        CombatEvent::Died(player)
+    );
+}
+
+#[derive(EnumEntityEvent, Clone, Copy, Debug)]
+#[enum_event(auto_propagate, propagate)]
+#[allow(dead_code)]
+enum UiEvent {
+    Clicked(Entity),
+}
+
+#[test]
+fn test_tuple_variants_propagate() {
+    let mut app = log_app();
+    let parent = app.world_mut().spawn_empty().id();
+    let child = app.world_mut().spawn(ChildOf(parent)).id();
+    app.world_mut()
+        .entity_mut(parent)
+        .observe(|_: On<ui_event::Clicked>, mut log: ResMut<Log>| {
+            log.push("parent");
+        });
+
+    UiEvent::Clicked(child).trigger_world(app.world_mut());
+
+    assert_eq!(logged(&app), vec!["parent"]);
+}
@@ -0,0 +1,86 @@
+//! Tests that the generated module, structs and fields follow the enum's
+//! visibility, and that `#[enum_event(vis = "...")]` overrides it.
+
+#![deny(private_interfaces, private_bounds)]
+
+use bevy::prelude::*;
+
+mod library {
+    use bevy_enum_event::{EnumEntityEvent, EnumEvent};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub(crate) struct Secret(pub(crate) u32);
+
+    // A crate-private payload type is fine in a crate-private enum
+    #[derive(EnumEvent, Clone, Copy, Debug)]
+    #[allow(dead_code)]
+    pub(crate) enum Internal {
+        Found { secret: crate::library::Secret },
+        Lost(crate::library::Secret),
+    }
+
+    #[derive(EnumEntityEvent, Clone, Copy, Debug)]
+    #[enum_event(any)]
+    #[allow(dead_code)]
+    pub enum Public {
+        Touched { entity: bevy::prelude::Entity },
+    }
+
+    // Private enums produce a private module whose items are visible to this module
+    #[derive(EnumEvent, Clone, Copy, Debug)]
+    #[allow(dead_code)]
+    enum Hidden {
+        Revealed(u32),
+    }
+
+    pub(crate) fn reveal() -> u32 {
+        let revealed = hidden::Revealed(4);
+        revealed.0
+    }
+
+    pub(crate) mod nested {
+        use bevy_enum_event::EnumEvent;
+
+        // Widen the generated items beyond the private enum
+        #[derive(EnumEvent, Clone, Copy, Debug)]
+        #[enum_event(vis = "pub(crate)")]
+        #[allow(dead_code)]
+        enum Widened {
+            Ping,
+            Pong(u8),
+        }
+    }
+}
+
+#[test]
+fn test_crate_private_enum_items() {
+    let found = library::internal::Found {
+        secret: library::Secret(3),
+    };
+    assert_eq!(found.secret, library::Secret(3));
+    assert_eq!(library::internal::Lost(library::Secret(1)).0 .0, 1);
+}
+
+#[test]
+fn test_public_enum_items() {
+    let entity = Entity::from_bits(5);
+    let touched = library::public::Touched { entity };
+    assert_eq!(touched.entity, entity);
+
+    let any = library::public::Any {
+        entity,
+        value: library::Public::Touched { entity },
+    };
+    assert_eq!(any.entity, entity);
+}
+
+#[test]
+fn test_private_enum_items_visible_to_parent() {
+    assert_eq!(library::reveal(), 4);
+}
+
+#[test]
+fn test_vis_override() {
+    let _ = library::nested::widened::Ping;
+    assert_eq!(library::nested::widened::Pong(7).0, 7);
+}